@@ -0,0 +1,660 @@
+//! Procedural macros for dimensioned
+//!
+//! These are meant to be used through dimensioned with its `macros` feature, and are documented
+//! there, in the `macros` module. Each macro here takes the path to dimensioned, followed by a
+//! semicolon, before its usual input; dimensioned fills that in.
+
+use proc_macro2::{Literal, TokenStream, TokenTree};
+use quote::quote;
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream};
+use syn::{
+    braced, parenthesized, parse_macro_input, token, Error, Expr, Ident, LitBool, LitInt, LitStr,
+    Path, Result, Token,
+};
+
+mod kw {
+    syn::custom_keyword!(base);
+    syn::custom_keyword!(derived);
+    syn::custom_keyword!(constants);
+    syn::custom_keyword!(fmt);
+    syn::custom_keyword!(symbols);
+}
+
+/// Create a new unit system, checking its definition first. See `dimensioned::macros`.
+#[proc_macro]
+pub fn make_units(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as Input);
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct Input {
+    krate: TokenStream,
+    system: Ident,
+    one: Ident,
+    unitless: Ident,
+    base_brace: token::Brace,
+    base: Vec<Base>,
+    derived: Vec<Derived>,
+    constants: Vec<Constant>,
+    fmt: LitBool,
+    symbols: Option<Path>,
+}
+
+struct Base {
+    constant: Ident,
+    unit: Ident,
+    print_as: Expr,
+    dims: Vec<Ident>,
+}
+
+struct Derived {
+    constant: Ident,
+    unit: Ident,
+    symbol: Option<LitStr>,
+    expr: UnitExpr,
+    scale: Option<Expr>,
+    dims: Vec<Ident>,
+}
+
+struct Constant {
+    constant: Ident,
+    unit: Ident,
+    value: Expr,
+    /// The `integer` flag, with its list of integer types if it has one.
+    flag: Option<TokenStream>,
+}
+
+/// The formula of a derived unit.
+enum UnitExpr {
+    Unit(Ident),
+    Product(Box<UnitExpr>, Box<UnitExpr>),
+    Quotient(Box<UnitExpr>, Box<UnitExpr>),
+    Power(Box<UnitExpr>, isize),
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut krate = TokenStream::new();
+        while !input.peek(Token![;]) {
+            krate.extend(Some(input.parse::<TokenTree>()?));
+        }
+        input.parse::<Token![;]>()?;
+
+        let system = input.parse()?;
+        input.parse::<Token![;]>()?;
+        let one = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let unitless = input.parse()?;
+        input.parse::<Token![;]>()?;
+
+        input.parse::<kw::base>()?;
+        let content;
+        let base_brace = braced!(content in input);
+        let base = parse_all(&content)?;
+
+        input.parse::<kw::derived>()?;
+        let content;
+        braced!(content in input);
+        let derived = parse_all(&content)?;
+
+        input.parse::<kw::constants>()?;
+        let content;
+        braced!(content in input);
+        let constants = parse_all(&content)?;
+
+        input.parse::<kw::fmt>()?;
+        input.parse::<Token![=]>()?;
+        let fmt = input.parse()?;
+        input.parse::<Token![;]>()?;
+
+        let symbols = if input.peek(kw::symbols) {
+            input.parse::<kw::symbols>()?;
+            input.parse::<Token![=]>()?;
+            let path = input.parse()?;
+            input.parse::<Token![;]>()?;
+            Some(path)
+        } else {
+            None
+        };
+
+        Ok(Input {
+            krate,
+            system,
+            one,
+            unitless,
+            base_brace,
+            base,
+            derived,
+            constants,
+            fmt,
+            symbols,
+        })
+    }
+}
+
+/// Parse entries until `input` is empty.
+fn parse_all<T: Parse>(input: ParseStream) -> Result<Vec<T>> {
+    let mut entries = Vec::new();
+    while !input.is_empty() {
+        entries.push(input.parse()?);
+    }
+    Ok(entries)
+}
+
+/// Parse any dimensions, which are each preceded by a comma.
+fn parse_dims(input: ParseStream) -> Result<Vec<Ident>> {
+    let mut dims = Vec::new();
+    while input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+        dims.push(input.parse()?);
+    }
+    Ok(dims)
+}
+
+impl Parse for Base {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let constant = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let unit = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let print_as = input.parse()?;
+        let dims = parse_dims(input)?;
+        input.parse::<Token![;]>()?;
+        Ok(Base {
+            constant,
+            unit,
+            print_as,
+            dims,
+        })
+    }
+}
+
+impl Parse for Derived {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let constant = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let unit = input.parse()?;
+        let symbol = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        input.parse::<Token![=]>()?;
+        let content;
+        parenthesized!(content in input);
+        let expr = content.parse()?;
+        let scale = if input.peek(Token![*]) {
+            input.parse::<Token![*]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let dims = parse_dims(input)?;
+        input.parse::<Token![;]>()?;
+        Ok(Derived {
+            constant,
+            unit,
+            symbol,
+            expr,
+            scale,
+            dims,
+        })
+    }
+}
+
+impl Parse for Constant {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let constant = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let unit = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        let flag = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let flag: Ident = input.parse()?;
+            if flag != "integer" {
+                return Err(Error::new(
+                    flag.span(),
+                    format!("unknown flag `{}`; the only flag is `integer`", flag),
+                ));
+            }
+            if input.peek(token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                let types = content.parse_terminated(Ident::parse, Token![,])?;
+                if types.is_empty() {
+                    return Err(content.error("expected a list of integer types"));
+                }
+                if let Some(t) = types
+                    .iter()
+                    .find(|t| !INTEGER_TYPES.iter().any(|i| *t == i))
+                {
+                    return Err(Error::new(
+                        t.span(),
+                        format!("`{}` is not a primitive integer type", t),
+                    ));
+                }
+                let types = types.iter();
+                Some(quote!(#flag(#(#types),*)))
+            } else {
+                Some(quote!(#flag))
+            }
+        } else {
+            None
+        };
+        input.parse::<Token![;]>()?;
+        Ok(Constant {
+            constant,
+            unit,
+            value,
+            flag,
+        })
+    }
+}
+
+impl Parse for UnitExpr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut expr = UnitExpr::parse_power(input)?;
+        loop {
+            if input.is_empty() {
+                return Ok(expr);
+            } else if input.peek(Token![*]) {
+                input.parse::<Token![*]>()?;
+                let rhs = UnitExpr::parse_power(input)?;
+                expr = UnitExpr::Product(Box::new(expr), Box::new(rhs));
+            } else if input.peek(Token![/]) {
+                input.parse::<Token![/]>()?;
+                let rhs = UnitExpr::parse_power(input)?;
+                expr = UnitExpr::Quotient(Box::new(expr), Box::new(rhs));
+            } else {
+                return Err(input.error("expected `*`, `/` or `^`"));
+            }
+        }
+    }
+}
+
+impl UnitExpr {
+    /// Parse a unit or a parenthesized expression, raised to a power if followed by `^`.
+    fn parse_power(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        let expr = if lookahead.peek(Ident) {
+            UnitExpr::Unit(input.parse()?)
+        } else if lookahead.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            content.parse()?
+        } else {
+            return Err(lookahead.error());
+        };
+
+        if !input.peek(Token![^]) {
+            return Ok(expr);
+        }
+        input.parse::<Token![^]>()?;
+        let negative = input.parse::<Option<Token![-]>>()?.is_some();
+        let power: LitInt = input.parse()?;
+        let power: isize = power.base10_parse()?;
+        let power = if negative { -power } else { power };
+        Ok(UnitExpr::Power(Box::new(expr), power))
+    }
+
+    /// Every unit named in this expression.
+    fn units<'a>(&'a self, out: &mut Vec<&'a Ident>) {
+        match self {
+            UnitExpr::Unit(unit) => out.push(unit),
+            UnitExpr::Product(a, b) | UnitExpr::Quotient(a, b) => {
+                a.units(out);
+                b.units(out);
+            }
+            UnitExpr::Power(a, _) => a.units(out),
+        }
+    }
+
+    /// The power of each base unit in this expression, or the first unit in it that isn't in
+    /// `units`.
+    fn eval(&self, units: &HashMap<String, Vec<isize>>) -> std::result::Result<Vec<isize>, &Ident> {
+        Ok(match self {
+            UnitExpr::Unit(unit) => units.get(&unit.to_string()).ok_or(unit)?.clone(),
+            UnitExpr::Product(a, b) => {
+                let (a, b) = (a.eval(units)?, b.eval(units)?);
+                a.iter().zip(b).map(|(a, b)| a + b).collect()
+            }
+            UnitExpr::Quotient(a, b) => {
+                let (a, b) = (a.eval(units)?, b.eval(units)?);
+                a.iter().zip(b).map(|(a, b)| a - b).collect()
+            }
+            UnitExpr::Power(a, power) => a.eval(units)?.iter().map(|a| a * power).collect(),
+        })
+    }
+}
+
+/// The types that constants may be defined for with `integer(...)`.
+const INTEGER_TYPES: [&str; 10] = [
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+/// Collect errors so that all of them are reported, rather than just the first.
+#[derive(Default)]
+struct Errors(Option<Error>);
+
+impl Errors {
+    fn push(&mut self, e: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(e),
+            None => self.0 = Some(e),
+        }
+    }
+
+    fn check(self) -> Result<()> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
+
+impl Input {
+    /// The power of each base unit for every unit in this system, by name, checking that every
+    /// derived unit can be resolved.
+    fn unit_exponents(&self, errors: &mut Errors) -> HashMap<String, Vec<isize>> {
+        let n = self.base.len();
+        let mut units = HashMap::new();
+        units.insert(self.unitless.to_string(), vec![0; n]);
+        for (i, b) in self.base.iter().enumerate() {
+            let mut e = vec![0; n];
+            e[i] = 1;
+            units.insert(b.unit.to_string(), e);
+        }
+
+        // Derived units may be defined in terms of ones that come later, so we keep going until
+        // we can't resolve any more.
+        let mut remaining: Vec<&Derived> = self.derived.iter().collect();
+        while !remaining.is_empty() {
+            let before = remaining.len();
+            remaining.retain(|d| match d.expr.eval(&units) {
+                Ok(e) => {
+                    units.insert(d.unit.to_string(), e);
+                    false
+                }
+                Err(_) => true,
+            });
+            if remaining.len() == before {
+                self.unresolved(&remaining, &units, errors);
+                break;
+            }
+        }
+        units
+    }
+
+    /// Report why each of the derived units in `remaining` can't be resolved: it names a unit
+    /// that doesn't exist, or its definition refers back to itself. Units that only depend on
+    /// others that can't be resolved have nothing to report, as the error is elsewhere.
+    fn unresolved(
+        &self,
+        remaining: &[&Derived],
+        units: &HashMap<String, Vec<isize>>,
+        errors: &mut Errors,
+    ) {
+        let deps: HashMap<String, Vec<String>> = remaining
+            .iter()
+            .map(|d| {
+                let mut names = Vec::new();
+                d.expr.units(&mut names);
+                let names = names.iter().map(|n| n.to_string());
+                let unresolved = names.filter(|n| !units.contains_key(n)).collect();
+                (d.unit.to_string(), unresolved)
+            })
+            .collect();
+
+        for d in remaining {
+            let mut names = Vec::new();
+            d.expr.units(&mut names);
+            for name in names {
+                if !units.contains_key(&name.to_string()) && !deps.contains_key(&name.to_string()) {
+                    errors.push(Error::new(
+                        name.span(),
+                        format!(
+                            "unknown unit `{}`; derived units are made of `{}`, base units and \
+                             other derived units",
+                            name, self.unitless
+                        ),
+                    ));
+                }
+            }
+
+            // Search the units that this one depends on for a way back to it.
+            let unit = d.unit.to_string();
+            let mut stack: Vec<&String> = deps[&unit].iter().collect();
+            let mut seen = Vec::new();
+            while let Some(next) = stack.pop() {
+                if *next == unit {
+                    errors.push(Error::new(
+                        d.unit.span(),
+                        format!("the definition of `{}` refers back to itself", d.unit),
+                    ));
+                    break;
+                }
+                if !seen.contains(&next) {
+                    seen.push(next);
+                    stack.extend(deps.get(next).into_iter().flatten());
+                }
+            }
+        }
+    }
+
+    /// Check that no name is defined twice.
+    fn check_names(&self, errors: &mut Errors) {
+        let units = Some(&self.unitless)
+            .into_iter()
+            .chain(self.base.iter().map(|b| &b.unit))
+            .chain(self.derived.iter().map(|d| &d.unit));
+        let constants = Some(&self.one)
+            .into_iter()
+            .chain(self.base.iter().map(|b| &b.constant))
+            .chain(self.derived.iter().map(|d| &d.constant))
+            .chain(self.constants.iter().map(|c| &c.constant));
+        for names in [units.collect::<Vec<_>>(), constants.collect()] {
+            for (i, name) in names.iter().enumerate() {
+                if names[..i].contains(name) {
+                    errors.push(Error::new(
+                        name.span(),
+                        format!("`{}` is defined more than once", name),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Check that each dimension is implemented only once for the same units, as they would
+    /// otherwise be the same type.
+    fn check_dims(&self, units: &HashMap<String, Vec<isize>>, errors: &mut Errors) {
+        let mut seen: HashMap<(String, &Vec<isize>), &Ident> = HashMap::new();
+        let dims = self
+            .base
+            .iter()
+            .map(|b| (&b.unit, &b.dims))
+            .chain(self.derived.iter().map(|d| (&d.unit, &d.dims)));
+        for (unit, dims) in dims {
+            let e = match units.get(&unit.to_string()) {
+                Some(e) => e,
+                None => continue,
+            };
+            for dim in dims {
+                match seen.get(&(dim.to_string(), e)) {
+                    Some(other) => errors.push(Error::new(
+                        dim.span(),
+                        format!(
+                            "`{}` has the same units as `{}`, which already implements `{}`",
+                            unit, other, dim
+                        ),
+                    )),
+                    None => {
+                        seen.insert((dim.to_string(), e), unit);
+                    }
+                }
+            }
+        }
+    }
+
+    fn expand(&self) -> Result<TokenStream> {
+        let mut errors = Errors::default();
+        if self.base.is_empty() {
+            errors.push(Error::new(
+                self.base_brace.span.join(),
+                "a unit system needs at least one base unit",
+            ));
+        }
+        self.check_names(&mut errors);
+        let units = self.unit_exponents(&mut errors);
+        self.check_dims(&units, &mut errors);
+        for c in &self.constants {
+            if !units.contains_key(&c.unit.to_string()) {
+                errors.push(Error::new(
+                    c.unit.span(),
+                    format!("unknown unit `{}` for constant `{}`", c.unit, c.constant),
+                ));
+            }
+        }
+        errors.check()?;
+
+        let base = self.base.iter().map(|b| {
+            let Base {
+                constant,
+                unit,
+                print_as,
+                dims,
+            } = b;
+            quote!(#constant: #unit, #print_as #(, #dims)*;)
+        });
+
+        // Derived units are passed on in terms of base units, so that they can be in any order
+        let unitless = &self.unitless;
+        let derived = self.derived.iter().map(|d| {
+            let Derived {
+                constant,
+                unit,
+                symbol,
+                scale,
+                dims,
+                ..
+            } = d;
+            let factors = self
+                .base
+                .iter()
+                .zip(&units[&unit.to_string()])
+                .filter(|&(_, &power)| power != 0)
+                .map(|(b, &power)| {
+                    let base = &b.unit;
+                    let abs = Literal::usize_unsuffixed(power.unsigned_abs());
+                    match power {
+                        1 => quote!(* #base),
+                        p if p < 0 => quote!(* #base ^ - #abs),
+                        _ => quote!(* #base ^ #abs),
+                    }
+                });
+            let symbol = symbol.iter();
+            let scale = scale.iter();
+            quote!(#constant: #unit #(, #symbol)* = (#unitless #(#factors)*) #(* #scale)* #(, #dims)*;)
+        });
+
+        let constants = self.constants.iter().map(|c| {
+            let Constant {
+                constant,
+                unit,
+                value,
+                flag,
+            } = c;
+            let flag = flag.iter();
+            quote!(#constant: #unit = #value #(, #flag)*;)
+        });
+
+        let Input {
+            krate,
+            system,
+            one,
+            fmt,
+            ..
+        } = self;
+        let symbols = self.symbols.iter();
+        Ok(quote! {
+            #krate::make_units! {
+                #system;
+                #one: #unitless;
+                base { #(#base)* }
+                derived { #(#derived)* }
+                constants { #(#constants)* }
+                fmt = #fmt;
+                #(symbols = #symbols;)*
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The errors in the derived units `derived` of a system with base units `Meter` and `Second`.
+    fn errors(derived: &str) -> Vec<String> {
+        let input: Input = syn::parse_str(&format!(
+            "dim; MS; ONE: Unitless; base {{ M: Meter, \"m\", Length; S: Second, \"s\", Time; }} \
+             derived {{ {} }} constants {{}} fmt = true;",
+            derived
+        ))
+        .unwrap();
+        let mut errors = Errors::default();
+        input.unit_exponents(&mut errors);
+        let errors = errors.check().err().into_iter().flatten();
+        errors.map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn unknown_units() {
+        let errors = errors(
+            "MPS2: MeterPerSecond2 = (MeterPerSecond / Second), Acceleration;
+             MPS: MeterPerSecond = (Metre / Second), Velocity;",
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("unknown unit `Metre`"));
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(
+            errors(
+                "A: Apple = (Banana * Meter);
+                 B: Banana = (Apple / Second);
+                 C: Cherry = (Apple * Apple);"
+            ),
+            [
+                "the definition of `Apple` refers back to itself",
+                "the definition of `Banana` refers back to itself",
+            ]
+        );
+        assert!(errors("A: Apple = (Apple * Meter);")[0].contains("refers back to itself"));
+    }
+
+    #[test]
+    fn integer_types() {
+        let parse = |constants: &str| {
+            syn::parse_str::<Input>(&format!(
+                "dim; MS; ONE: Unitless; base {{ M: Meter, \"m\", Length; }} derived {{}} \
+                 constants {{ {} }} fmt = true;",
+                constants
+            ))
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+        };
+        assert_eq!(parse("AU: Meter = 1.5e11, integer(i64, u64);"), Ok(()));
+        assert_eq!(
+            parse("AU: Meter = 1.5e11, integer(i64, u65);"),
+            Err("`u65` is not a primitive integer type".to_string())
+        );
+        assert_eq!(
+            parse("AU: Meter = 1.5e11, integer();"),
+            Err("unexpected end of input, expected a list of integer types".to_string())
+        );
+    }
+}
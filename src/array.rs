@@ -1,4 +1,4 @@
-//! Tools for converting from type arrays of type numbers to generic arrays.
+//! Tools for converting from type arrays of type numbers to generic arrays and arrays.
 //!
 //! This module may change, and will likely be removed someday if this functionality is implemented into typenum.
 //!
@@ -101,6 +101,62 @@ where
     }
 }
 
+/// Implemented for `TArr` (a type-level array of type numbers) of length `N`, this gives the
+/// equivalent array as an associated constant, so it can be used in `const` contexts.
+///
+/// It is implemented for arrays of up to 32 elements.
+///
+/// # Example
+/// ```rust
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// use dim::typenum::consts::*;
+/// type TArr = tarr![P3, P2, N5, N8, P2];
+///
+/// fn main() {
+///     use dim::array::ToArray;
+///     const X: [isize; 5] = <TArr as ToArray<5>>::ARRAY;
+///
+///     assert_eq!(X, [3, 2, -5, -8, 2]);
+/// }
+/// ```
+pub trait ToArray<const N: usize> {
+    /// The array of integers equivalent to this `TArr` of type numbers.
+    const ARRAY: [isize; N];
+}
+
+impl ToArray<0> for ATerm {
+    const ARRAY: [isize; 0] = [];
+}
+
+/// Create an array from `head` followed by the elements of `tail`; `M` must be `N + 1`.
+const fn prepend<const N: usize, const M: usize>(head: isize, tail: [isize; N]) -> [isize; M] {
+    let mut array = [0; M];
+    array[0] = head;
+    let mut i = 0;
+    while i < N {
+        array[i + 1] = tail[i];
+        i += 1;
+    }
+    array
+}
+
+macro_rules! impl_to_array {
+    ($($N:expr => $M:expr),*) => (
+        $(impl<V: Integer, A: ToArray<$N>> ToArray<$M> for TArr<V, A> {
+            const ARRAY: [isize; $M] = prepend(V::ISIZE, A::ARRAY);
+        })*
+    );
+}
+
+impl_to_array!(
+    0 => 1, 1 => 2, 2 => 3, 3 => 4, 4 => 5, 5 => 6, 6 => 7, 7 => 8, 8 => 9, 9 => 10, 10 => 11,
+    11 => 12, 12 => 13, 13 => 14, 14 => 15, 15 => 16, 16 => 17, 17 => 18, 18 => 19, 19 => 20,
+    20 => 21, 21 => 22, 22 => 23, 23 => 24, 24 => 25, 25 => 26, 26 => 27, 27 => 28, 28 => 29,
+    29 => 30, 30 => 31, 31 => 32
+);
+
 #[test]
 fn test_array() {
     use typenum::consts::*;
@@ -108,4 +164,5 @@ fn test_array() {
     let a = A::to_ga();
 
     assert_eq!(a, arr![isize; 1, -3, 4]);
+    assert_eq!(<A as ToArray<3>>::ARRAY, [1, -3, 4]);
 }
@@ -33,19 +33,24 @@ Note: this system is incomplete. More derived units and constants are coming.
             CM2PS2: Centimeter2PerSecond2 = Centimeter2 / Second2;
             CM2PS3: Centimeter2PerSecond3 = Centimeter2 / Second3;
 
-            CM3PS: Centimeter3PerSecond = Centimeter3 / Second;
+            CM3PS: Centimeter3PerSecond = Centimeter3 / Second, VolumetricFlowRate;
             CM3PS2: Centimeter3PerSecond2 = Centimeter3 / Second2;
             CM3PS3: Centimeter3PerSecond3 = Centimeter3 / Second3;
 
             GAL: Gal = Centimeter / Second2, Acceleration;
             DYN: Dyne = Gram *  Gal, Force;
-            ERG: Erg = Dyne * Centimeter, Energy;
+            ERG: Erg = Dyne * Centimeter, Energy, Torque;
             ERGPS: ErgPerSecond = Erg / Second, Power;
             BA: Barye = Dyne / Centimeter2, Pressure;
-            P: Poise = Gram / Centimeter / Second;
-            ST: Stokes = Centimeter2 / Second;
+            P: Poise = Gram / Centimeter / Second, DynamicViscosity;
+            ST: Stokes = Centimeter2 / Second, KinematicViscosity;
             K: Kayser = Unitless / Centimeter, ReciprocalLength;
 
+            GPCM3: GramPerCentimeter3 = Gram / Centimeter3, Density;
+            GPS: GramPerSecond = Gram / Second, MassFlowRate;
+            DYNS: DyneSecond = Dyne * Second, Momentum;
+            DYNPCM: DynePerCentimeter = Dyne / Centimeter, SurfaceTension;
+
             STATC: StatCoulomb = SqrtGram * SqrtCentimeter * Centimeter / Second;
             STATA: StatAmpere = StatCoulomb / Second;
             STATV: StatVolt = Erg / StatCoulomb;
@@ -55,6 +60,7 @@ Note: this system is incomplete. More derived units and constants are coming.
         ),
         fmt: false,
         from: vec!["SI", "MKS"],
+        partial_from: vec!["SI"],
         refl_blacklist: Vec::new(),
     }
 }
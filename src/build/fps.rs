@@ -6,7 +6,9 @@ pub fn new() -> System {
         module: "fps",
         doc_prelude: "The foot, pound, second system, using the mass pound as a base unit.
 
-Note: this system is incomplete. More derived units and constants are coming.
+Force is measured in poundals, the force that accelerates one pound at one foot per second
+squared. The pound-force, `LBF`, is defined as a constant in terms of standard gravity, as is the
+slug, `SLUG`, the mass that one pound-force accelerates at one foot per second squared.
 
 ",
         base: base_units!(
@@ -17,13 +19,52 @@ Note: this system is incomplete. More derived units and constants are coming.
         derived: derived_units!(
             FT: Foot = SqrtFoot * SqrtFoot, Length;
             LB: Pound = SqrtPound * SqrtPound, Mass;
+
+            FT2: Foot2 = Foot * Foot, Area;
+            FT3: Foot3 = Foot2 * Foot, Volume;
+
+            S2: Second2 = Second * Second;
+            S3: Second3 = Second2 * Second;
+
+            FTPS: FootPerSecond = Foot / Second, Velocity;
+            FTPS2: FootPerSecond2 = Foot / Second2, Acceleration;
+            FTPS3: FootPerSecond3 = Foot / Second3, Jerk;
+
+            FT2PS: Foot2PerSecond = Foot2 / Second, KinematicViscosity;
+            FT3PS: Foot3PerSecond = Foot3 / Second, VolumetricFlowRate;
+            LBPS: PoundPerSecond = Pound / Second, MassFlowRate;
+            LBPFT3: PoundPerFoot3 = Pound / Foot3, Density;
+
+            PDL: Poundal = Pound * FootPerSecond2, Force;
+            FTPDL: FootPoundal = Poundal * Foot, Energy, Torque;
+            FTPDLPS: FootPoundalPerSecond = FootPoundal / Second, Power;
+            PDLPFT2: PoundalPerFoot2 = Poundal / Foot2, Pressure;
+            PDLS: PoundalSecond = Poundal * Second, Momentum;
+            PDLSPFT2: PoundalSecondPerFoot2 = PoundalSecond / Foot2, DynamicViscosity;
+            PDLPFT: PoundalPerFoot = Poundal / Foot, SurfaceTension;
+        ),
+        constants: constants!(
+            MIN: Second = 60.0 * S.value_unsafe, "Minute", integer;
+            HR: Second = 60.0 * MIN.value_unsafe, "Hour", integer;
+            DAY: Second = 24.0 * HR.value_unsafe, "Day", integer;
+
+            IN: Foot = FT.value_unsafe / 12.0, "Inch";
+            YD: Foot = 3.0 * FT.value_unsafe, "Yard", integer;
+            MI: Foot = 5_280.0 * FT.value_unsafe, "Mile", integer;
+
+            OZ: Pound = LB.value_unsafe / 16.0, "Ounce";
+
+            G0: FootPerSecond2 = 9.80665 / 0.3048 * FTPS2.value_unsafe, "Standard gravity";
+            LBF: Poundal = LB.value_unsafe * G0.value_unsafe, "Pound force";
+            SLUG: Pound = LBF.value_unsafe / FTPS2.value_unsafe, "Slug";
+            PSI: PoundalPerFoot2 = LBF.value_unsafe / IN.value_unsafe / IN.value_unsafe, "Pound per square inch";
+            FTLBF: FootPoundal = LBF.value_unsafe * FT.value_unsafe, "Foot pound force";
+            BTU: FootPoundal = 1_055.055_852_62 / 0.042_140_110_093_804_8 * FTPDL.value_unsafe, "British thermal unit (International Table)";
+            HP: FootPoundalPerSecond = 550.0 * FTLBF.value_unsafe / S.value_unsafe, "Horsepower (mechanical)";
         ),
-        constants: constants!(),
         fmt: false,
-        from: vec![
-            // "SI",
-            // "MKS",
-        ],
-        refl_blacklist: Vec::new(),
+        from: vec!["SI", "MKS"],
+        partial_from: vec!["SI"],
+        refl_blacklist: vec!["SQRTFT", "SQRTLB"],
     }
 }
@@ -18,11 +18,21 @@ Note: this system is incomplete. More derived units and constants are coming.
             M: Meter = SqrtMeter * SqrtMeter, Length;
             KG: Kilogram = SqrtKilogram * SqrtKilogram, Mass;
 
+            M2: Meter2 = Meter * Meter, Area;
+            M3: Meter3 = Meter2 * Meter, Volume;
+
             MPS: MeterPerSecond = Meter / Second, Velocity;
+            M2PS: Meter2PerSecond = Meter2 / Second, KinematicViscosity;
+            M3PS: Meter3PerSecond = Meter3 / Second, VolumetricFlowRate;
+
+            KGPM3: KilogramPerMeter3 = Kilogram / Meter3, Density;
+            KGPS: KilogramPerSecond = Kilogram / Second, MassFlowRate;
+            KGMPS: KilogramMeterPerSecond = Kilogram * MeterPerSecond, Momentum;
         ),
         constants: constants!(),
         fmt: false,
-        from: vec!["SI", "CGS"],
+        from: vec!["SI", "CGS", "FPS"],
+        partial_from: vec!["SI"],
         refl_blacklist: Vec::new(),
     }
 }
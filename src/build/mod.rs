@@ -8,6 +8,7 @@
 //! generate a test to ensure that `MIN == 60.0 * S`. This would not be necessary if we could
 //! perform these operations at compile time.
 
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[derive(Debug)]
@@ -22,6 +23,7 @@ pub struct BaseUnit {
 pub struct DerivedUnit {
     pub name: &'static str,
     pub constant: &'static str,
+    pub symbol: &'static str,
     pub expression: &'static str,
     pub dim: &'static str,
 }
@@ -32,6 +34,33 @@ pub struct Constant {
     pub unit: &'static str,
     pub value: &'static str,
     pub name: &'static str,
+    /// The integer types for which this constant is also defined, such as `["i64", "u64"]`.
+    pub integer: &'static [&'static str],
+}
+
+impl DerivedUnit {
+    /// The unit expression and the scale of this unit. A unit that isn't worth 1 of the units it is
+    /// made of is written with its scale last, as in `(Gram * Meter / Second2) * 1000.0`.
+    fn parts(&self) -> (&'static str, Option<&'static str>) {
+        let e = self.expression;
+        let mut depth = 0;
+        for (i, c) in e.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => (),
+            }
+            if depth == 0 {
+                return match e[i + 1..].strip_prefix(" * ") {
+                    Some(scale) if c == ')' && scale.starts_with(|c: char| c.is_ascii_digit()) => {
+                        (&e[1..i], Some(scale))
+                    }
+                    _ => (e, None),
+                };
+            }
+        }
+        (e, None)
+    }
 }
 
 impl Constant {
@@ -49,6 +78,9 @@ pub struct System {
     pub derived: Vec<DerivedUnit>,
     pub constants: Vec<Constant>,
     pub from: Vec<&'static str>,
+    /// Systems in `from` that can only be converted to this one for some of their units, so
+    /// round-trip tests of their constants through this system are not generated.
+    pub partial_from: Vec<&'static str>,
     pub refl_blacklist: Vec<&'static str>,
     pub fmt: bool,
 }
@@ -72,13 +104,16 @@ Following, we list all of the [base units](#base-units), [derived units](#derive
         }
 
         writeln!(f, "# Derived Units")?;
-        writeln!(f, "Constant | Unit | Unit Definition | Dimension")?;
-        writeln!(f, "---|---|---|---")?;
+        writeln!(
+            f,
+            "Constant | Unit | Print Symbol | Unit Definition | Dimension"
+        )?;
+        writeln!(f, "---|---|---|---|---")?;
         for d in &self.derived {
             writeln!(
                 f,
-                "{} | {} | {} | {}",
-                d.constant, d.name, d.expression, d.dim
+                "{} | {} | {} | {} | {}",
+                d.constant, d.name, d.symbol, d.expression, d.dim
             )?;
         }
 
@@ -128,6 +163,186 @@ Following, we list all of the [base units](#base-units), [derived units](#derive
     }
 }
 
+impl System {
+    /// The power of each base unit for every unit in this system, by name.
+    fn unit_exponents(&self) -> HashMap<&'static str, Vec<isize>> {
+        let n = self.base.len();
+        let mut exponents = HashMap::new();
+        exponents.insert("Unitless", vec![0; n]);
+        for (i, b) in self.base.iter().enumerate() {
+            let mut e = vec![0; n];
+            e[i] = 1;
+            exponents.insert(b.name, e);
+        }
+
+        // Derived units may be defined in terms of ones that come later, so we keep going until
+        // we can't resolve any more.
+        let mut remaining: Vec<&DerivedUnit> = self.derived.iter().collect();
+        while !remaining.is_empty() {
+            let before = remaining.len();
+            remaining.retain(|d| match eval_expression(d.parts().0, &exponents) {
+                Some(e) => {
+                    exponents.insert(d.name, e);
+                    false
+                }
+                None => true,
+            });
+            if remaining.len() == before {
+                panic!("could not resolve the definition of {}", remaining[0].name);
+            }
+        }
+
+        exponents
+    }
+
+    /// The entries of the symbol table, as `(symbol, constant, unit)`, in order of precedence and
+    /// without any that would be shadowed by an earlier entry.
+    fn symbols(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        let tokens = self
+            .base
+            .iter()
+            .map(|b| (b.token, b.constant, b.name))
+            .chain(
+                self.derived
+                    .iter()
+                    .filter(|d| !d.symbol.is_empty())
+                    .map(|d| (d.symbol, d.constant, d.name)),
+            );
+        let constants = self
+            .base
+            .iter()
+            .map(|b| (b.constant, b.constant, b.name))
+            .chain(
+                self.derived
+                    .iter()
+                    .map(|d| (d.constant, d.constant, d.name)),
+            )
+            .chain(
+                self.constants
+                    .iter()
+                    .map(|c| (c.constant, c.constant, c.unit)),
+            );
+
+        let mut seen = HashSet::new();
+        tokens
+            .chain(constants)
+            .filter(|&(symbol, _, _)| seen.insert(symbol))
+            .collect()
+    }
+
+    fn symbol_table(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let exponents = self.unit_exponents();
+
+        write!(
+            f,
+            "
+    /// The units that can be used by name when parsing quantities in this system.
+    ///
+    /// It contains the print token of each base unit, the print symbol of each derived unit
+    /// that has one, and the constant for every unit and constant defined in this system.
+    pub const SYMBOLS: &[crate::parse::Symbol] = &["
+        )?;
+        for (symbol, constant, unit) in self.symbols() {
+            let e = exponents
+                .get(unit)
+                .unwrap_or_else(|| panic!("unknown unit {} of {}", unit, constant));
+            write!(
+                f,
+                "
+        crate::parse::Symbol {{
+            symbol: \"{}\",
+            value: f64consts::{}.value_unsafe,
+            exponents: &{:?},
+        }},",
+                symbol, constant, e
+            )?;
+        }
+        writeln!(
+            f,
+            "
+    ];"
+        )
+    }
+}
+
+/// The power of each base unit for the derived unit expression `expression`, such as
+/// `Kilogram * (Meter / Second) ^ 2`, or `None` if it uses a unit not in `exponents`.
+fn eval_expression(expression: &str, exponents: &HashMap<&str, Vec<isize>>) -> Option<Vec<isize>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        } else {
+            tokens.push(c.to_string());
+            chars.next();
+        }
+    }
+
+    let mut tokens = tokens.iter().map(|t| t.as_str()).peekable();
+    let e = eval_product(&mut tokens, exponents)?;
+    assert!(
+        tokens.next().is_none(),
+        "bad derived unit expression {}",
+        expression
+    );
+    Some(e)
+}
+
+fn eval_product<'a>(
+    tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+    exponents: &HashMap<&str, Vec<isize>>,
+) -> Option<Vec<isize>> {
+    let mut e = eval_power(tokens, exponents)?;
+    while let Some(&op) = tokens.peek().filter(|&&t| t == "*" || t == "/") {
+        tokens.next();
+        let sign = if op == "*" { 1 } else { -1 };
+        for (a, b) in e.iter_mut().zip(eval_power(tokens, exponents)?) {
+            *a += sign * b;
+        }
+    }
+    Some(e)
+}
+
+fn eval_power<'a>(
+    tokens: &mut std::iter::Peekable<impl Iterator<Item = &'a str>>,
+    exponents: &HashMap<&str, Vec<isize>>,
+) -> Option<Vec<isize>> {
+    let mut e = match tokens.next() {
+        Some("(") => {
+            let e = eval_product(tokens, exponents)?;
+            assert_eq!(tokens.next(), Some(")"), "unbalanced parentheses");
+            e
+        }
+        Some(unit) => exponents.get(unit)?.clone(),
+        None => panic!("missing unit in derived unit expression"),
+    };
+    if tokens.peek() == Some(&"^") {
+        tokens.next();
+        let sign = if tokens.peek() == Some(&"-") {
+            tokens.next();
+            -1
+        } else {
+            1
+        };
+        let power: isize = tokens
+            .next()
+            .and_then(|t| t.parse().ok())
+            .expect("powers must be integers");
+        for a in &mut e {
+            *a *= sign * power;
+        }
+    }
+    Some(e)
+}
+
 impl fmt::Display for System {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         writeln!(f, "/**\n{}\n", self.doc_prelude)?;
@@ -172,10 +387,18 @@ pub mod {} {{
                 "" => String::new(),
                 d => format!(", {}", d),
             };
+            let symbol = match unit.symbol {
+                "" => String::new(),
+                s => format!(", \"{}\"", s),
+            };
+            let (expression, scale) = match unit.parts() {
+                (e, Some(scale)) => (e, format!(" * {}", scale)),
+                (e, None) => (e, String::new()),
+            };
             writeln!(
                 f,
-                "            {}: {} = ({}){};",
-                unit.constant, unit.name, unit.expression, dim
+                "            {}: {}{} = ({}){}{};",
+                unit.constant, unit.name, symbol, expression, scale, dim
             )?;
         }
 
@@ -187,14 +410,23 @@ pub mod {} {{
         )?;
 
         for c in &self.constants {
-            writeln!(f, "            {}: {} = {};", c.constant, c.unit, c.value)?;
+            let flag = match c.integer {
+                [] => String::new(),
+                DEFAULT_INTEGER_TYPES => ", integer".to_string(),
+                types => format!(", integer({})", types.join(", ")),
+            };
+            writeln!(
+                f,
+                "            {}: {} = {}{};",
+                c.constant, c.unit, c.value, flag
+            )?;
         }
 
         write!(
             f,
             "        }}
 
-        fmt = {0};
+        fmt = {0};{2}
     }}
 
     #[cfg(feature = \"serde\")]
@@ -208,11 +440,21 @@ pub mod {} {{
     impl_rand!({1});
 
     pub use self::f64consts::*;
-
 ",
-            self.fmt, self.name,
+            self.fmt,
+            self.name,
+            if self.fmt {
+                "\n        symbols = SYMBOLS;"
+            } else {
+                ""
+            },
         )?;
 
+        // Systems without `fmt` do their own parsing, so they have no use for a symbol table.
+        if self.fmt {
+            self.symbol_table(f)?;
+        }
+
         write!(
             f,
             "
@@ -235,6 +477,40 @@ pub mod {} {{
                 c.nice_value()
             )?;
         }
+        for c in &self.constants {
+            for t in c.integer {
+                // the pointer-sized modules are `isize_consts` and `usize_consts`
+                let sep = if t.ends_with("size") { "_" } else { "" };
+                write!(
+                    f,
+                    "
+        assert_eq!({1}{2}consts::{0}.value_unsafe as f64, {0}.value_unsafe);",
+                    c.constant, t, sep
+                )?;
+            }
+        }
+        write!(
+            f,
+            "
+    }}"
+        )?;
+
+        write!(
+            f,
+            "
+    /// Test that every symbol parses as the constant it names.
+    #[test]
+    fn test_{}_symbols() {{",
+            self.module
+        )?;
+        for (symbol, constant, unit) in self.symbols().into_iter().filter(|_| self.fmt) {
+            write!(
+                f,
+                "
+        assert_eq!(\"2 {}\".parse::<{}<f64>>().unwrap(), 2.0 * {});",
+                symbol, unit, constant
+            )?;
+        }
         write!(
             f,
             "
@@ -385,29 +661,40 @@ macro_rules! base_units {
             name: stringify!($unit),
             constant: stringify!($constant),
             token: stringify!($token),
-            dim: stringify!($($dim)*),
+            dim: stringify!($($dim),*),
         }),*]
     );
 }
 
 macro_rules! derived_units {
-    ($($constant:ident: $unit:ident =  $e:expr $(, $dim:ident)*;)* ) => (
+    ($($constant:ident: $unit:ident $(, $symbol:literal)? =  $e:expr $(, $dim:ident)*;)* ) => (
         vec![$(DerivedUnit{
             name: stringify!($unit),
             constant: stringify!($constant),
+            symbol: concat!($($symbol)?),
             expression: stringify!($e),
-            dim: stringify!($($dim)*),
+            dim: stringify!($($dim),*),
         }),*]
     );
 }
 
+/// The integer types of constants marked `integer` without a list of types.
+const DEFAULT_INTEGER_TYPES: &[&str] = &["i32", "i64", "u32", "u64"];
+
 macro_rules! constants {
-    ($($constant:ident: $unit:ident =  $e:expr, $name: expr;)* ) => (
+    ($($constant:ident: $unit:ident =  $e:expr, $name: expr
+       $(, $flag:ident $(($($int:ident),+))?)?;)* ) => (
         vec![$(Constant{
             unit: stringify!($unit),
             constant: stringify!($constant),
             value: stringify!($e),
             name: $name,
+            integer: match (stringify!($($flag)?), &[$($($(stringify!($int)),+)?)?] as &[&str]) {
+                ("", _) => &[],
+                ("integer", []) => DEFAULT_INTEGER_TYPES,
+                ("integer", types) => types,
+                (flag, _) => panic!("unknown flag {} for constant {}", flag, stringify!($constant)),
+            },
         }),*]
     );
 }
@@ -425,12 +712,38 @@ mod cgs;
 mod fps;
 mod mks;
 mod si;
+mod sia;
 mod ucum;
 
 use std::io::Write;
 
+/// The minor version of the compiler that is building us, if we can tell.
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = std::env::var_os("RUSTC")?;
+    let output = std::process::Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()?;
+    let version = std::str::from_utf8(&output.stdout).ok()?;
+    version.split('.').nth(1)?.parse().ok()
+}
+
 fn main() {
-    let systems = [si::new(), ucum::new(), mks::new(), cgs::new(), fps::new()];
+    // `#[diagnostic::on_unimplemented]` needs Rust 1.78, so we only give our own error messages
+    // on compilers that have it.
+    println!("cargo:rustc-check-cfg=cfg(diagnostic_namespace)");
+    if matches!(rustc_minor_version(), Some(minor) if minor >= 78) {
+        println!("cargo:rustc-cfg=diagnostic_namespace");
+    }
+
+    let systems = [
+        si::new(),
+        sia::new(),
+        ucum::new(),
+        mks::new(),
+        cgs::new(),
+        fps::new(),
+    ];
     for s in &systems {
         make_system(s);
     }
@@ -587,7 +900,10 @@ mod constant_conversion {{
             .chain(s.constants.iter().map(|c| c.constant))
             .collect();
         for s2 in systems.iter().filter(|s2| s2.name != s.name) {
-            if s.from.iter().any(|&f| f == s2.name) && s2.from.iter().any(|&f| f == s.name) {
+            if s.from.contains(&s2.name)
+                && s2.from.contains(&s.name)
+                && !s2.partial_from.contains(&s.name)
+            {
                 for c in constants1
                     .iter()
                     .filter(|&c| !s.refl_blacklist.iter().any(|b| c == b))
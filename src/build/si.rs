@@ -20,61 +20,61 @@ Where experimental values are used for constants, the values are obtained from t
             MOL: Mole, mol, AmountOfSubstance;
         ),
         derived: derived_units!(
-            HZ: Hertz = Unitless / Second, Frequency;
-            N: Newton = Kilogram * Meter / Second2, Force;
-            PA: Pascal = Newton / Meter2, Pressure;
-            J: Joule = Newton * Meter, Energy;
-            W: Watt = Joule / Second, Power;
-            C: Coulomb = Second * Ampere, Charge;
-            V: Volt = Watt / Ampere, ElectricPotential;
-            F: Farad = Coulomb / Volt, Capacitance;
-            OHM: Ohm = Volt / Ampere, Resistance;
-            SIE: Siemens = Ampere / Volt, Conductance;
-            WB: Weber = Joule / Ampere, MagneticFlux;
-            T: Tesla = Weber / Meter2;
-            H: Henry = Ohm * Second, Inductance;
-            LM: Lumen = Candela;
-            LX: Lux = Candela / Meter2;
-            BQ: Becquerel = Hertz;
-            GY: Gray = Joule / Kilogram;
-            SV: Sievert = Gray;
-            KAT: Katal = Mole / Second;
-
-            M2: Meter2 = Meter * Meter, Area;
-            M3: Meter3 = Meter2 * Meter, Volume;
+            HZ: Hertz, "Hz" = Unitless / Second, Frequency, AngularVelocity;
+            N: Newton, "N" = Kilogram * Meter / Second^2, Force;
+            PA: Pascal, "Pa" = Newton / Meter2, Pressure;
+            J: Joule, "J" = Newton * Meter, Energy, Torque;
+            W: Watt, "W" = Joule / Second, Power;
+            C: Coulomb, "C" = Second * Ampere, Charge;
+            V: Volt, "V" = Watt / Ampere, ElectricPotential;
+            F: Farad, "F" = Coulomb / Volt, Capacitance;
+            OHM: Ohm, "Ω" = Volt / Ampere, Resistance;
+            SIE: Siemens, "S" = Ampere / Volt, Conductance;
+            WB: Weber, "Wb" = Joule / Ampere, MagneticFlux;
+            T: Tesla, "T" = Weber / Meter2, MagneticFluxDensity;
+            H: Henry, "H" = Ohm * Second, Inductance;
+            LM: Lumen, "lm" = Candela, LuminousFlux;
+            LX: Lux = Candela / Meter2, Illuminance;
+            BQ: Becquerel = Hertz, Radioactivity;
+            GY: Gray, "Gy" = Joule / Kilogram, AbsorbedDose;
+            SV: Sievert = Gray, EquivalentDose;
+            KAT: Katal, "kat" = Mole / Second, CatalyticActivity;
+
+            M2: Meter2 = Meter^2, Area;
+            M3: Meter3 = Meter^3, Volume;
 
             PM: PerMeter = Unitless / Meter, ReciprocalLength;
-            PM2: PerMeter2 = PerMeter / Meter;
-            PM3: PerMeter3 = PerMeter2 / Meter;
+            PM2: PerMeter2 = Meter^-2;
+            PM3: PerMeter3 = Meter^-3;
 
-            S2: Second2 = Second * Second;
-            S3: Second3 = Second2 * Second;
-            S4: Second4 = Second3 * Second;
+            S2: Second2 = Second^2;
+            S3: Second3 = Second^3;
+            S4: Second4 = Second^4;
 
-            PS2: PerSecond2 = Unitless / Second2;
-            PS3: PerSecond3 = Unitless / Second3;
-            PS4: PerSecond4 = Unitless / Second4;
+            PS2: PerSecond2 = Second^-2, AngularAcceleration;
+            PS3: PerSecond3 = Second^-3;
+            PS4: PerSecond4 = Second^-4;
 
             MPS: MeterPerSecond = Meter / Second, Velocity;
             MPS2: MeterPerSecond2 = Meter / Second2, Acceleration;
             MPS3: MeterPerSecond3 = Meter / Second3, Jerk;
             MPS4: MeterPerSecond4 = Meter / Second4;
 
-            M2PS: Meter2PerSecond = Meter2 / Second;
+            M2PS: Meter2PerSecond = Meter2 / Second, KinematicViscosity;
             M2PS2: Meter2PerSecond2 = Meter2 / Second2;
             M2PS3: Meter2PerSecond3 = Meter2 / Second3;
 
-            M3PS: Meter3PerSecond = Meter3 / Second;
+            M3PS: Meter3PerSecond = Meter3 / Second, VolumetricFlowRate;
             M3PS2: Meter3PerSecond2 = Meter3 / Second2;
             M3PS3: Meter3PerSecond3 = Meter3 / Second3;
 
-            NS: NewtonSecond = Newton * Second;
+            NS: NewtonSecond = Newton * Second, Momentum;
             JS: JouleSecond = Joule * Second;
             NPS: NewtonPerSecond = Newton / Second;
 
             KGPM: KilogramPerMeter = Kilogram / Meter;
             KGPM2: KilogramPerMeter2 = Kilogram / Meter2;
-            KGPM3: KilogramPerMeter3 = Kilogram / Meter3;
+            KGPM3: KilogramPerMeter3 = Kilogram / Meter3, Density;
 
             MPKG: MeterPerKilogram = Meter / Kilogram;
             M2PKG: Meter2PerKilogram = Meter2 / Kilogram;
@@ -82,36 +82,36 @@ Where experimental values are used for constants, the values are obtained from t
 
             MOLPM: MolePerMeter = Mole / Meter;
             MOLPM2: MolePerMeter2 = Mole / Meter2;
-            MOLPM3: MolePerMeter3 = Mole / Meter3;
+            MOLPM3: MolePerMeter3 = Mole / Meter3, Concentration;
 
             MPMOL: MeterPerMole = Meter / Mole;
             M2PMOL: Meter2PerMole = Meter2 / Mole;
             M3PMOL: Meter3PerMole = Meter3 / Mole;
 
-            JPK: JoulePerKelvin = Joule / Kelvin;
+            JPK: JoulePerKelvin = Joule / Kelvin, HeatCapacity, Entropy;
             JPKMOL: JoulePerKelvinMole = JoulePerKelvin / Mole;
-            JPKGK: JoulePerKilogramKelvin = JoulePerKelvin / Kilogram;
+            JPKGK: JoulePerKilogramKelvin = JoulePerKelvin / Kilogram, SpecificHeatCapacity;
             JPMOL: JoulePerMole = Joule / Mole;
             JPKG: JoulePerKilogram = Joule / Kilogram;
             JPM2: JoulePerMeter2 = Joule / Meter2;
             JPM3: JoulePerMeter3 = Joule / Meter3;
 
-            NPM: NewtonPerMeter = JoulePerMeter2;
-            WPM2: WattPerMeter2 = Watt / Meter2;
-            WPMK: WattPerMeterKelvin = Watt / Meter / Kelvin;
+            NPM: NewtonPerMeter = JoulePerMeter2, SurfaceTension;
+            WPM2: WattPerMeter2 = Watt / Meter2, HeatFluxDensity;
+            WPMK: WattPerMeterKelvin = Watt / Meter / Kelvin, ThermalConductivity;
 
-            PAS: PascalSecond = Pascal * Second;
+            PAS: PascalSecond = Pascal * Second, DynamicViscosity;
 
             CPM2: CoulombPerMeter2 = Coulomb / Meter2;
             CPM3: CoulombPerMeter3 = Coulomb / Meter3;
-            APM2: AmperePerMeter2 = Ampere / Meter2;
-            SIEPM: SiemensPerMeter = Siemens / Meter;
+            APM2: AmperePerMeter2 = Ampere / Meter2, CurrentDensity;
+            SIEPM: SiemensPerMeter = Siemens / Meter, ElectricalConductivity;
             SIEM2PMOL: SiemensMeter2PerMole = Siemens * Meter2 / Mole;
-            FPM: FaradPerMeter = Farad / Meter;
-            HPM: HenryPerMeter = Henry / Meter;
-            VPM: VoltPerMeter = Volt / Meter;
-            APM: AmperePerMeter = Ampere / Meter;
-            CDPM2: CandelaPerMeter2 = Candela / Meter2;
+            FPM: FaradPerMeter = Farad / Meter, Permittivity;
+            HPM: HenryPerMeter = Henry / Meter, Permeability;
+            VPM: VoltPerMeter = Volt / Meter, ElectricField;
+            APM: AmperePerMeter = Ampere / Meter, MagneticFieldStrength;
+            CDPM2: CandelaPerMeter2 = Candela / Meter2, Luminance;
             LMS: LumenSecond = Lumen * Second;
             LXS: LuxSecond = Lux * Second;
             CPKG: CoulombPerKilogram = Coulomb / Kilogram;
@@ -119,8 +119,8 @@ Where experimental values are used for constants, the values are obtained from t
             OHMM: OhmMeter = Ohm * Meter;
             CPM: CoulombPerMeter = Coulomb / Meter;
             MOLPKG: MolePerKilogram = Mole / Kilogram;
-            KGPMOL: KilogramPerMole = Kilogram / Mole;
-            KGPS: KilogramPerSecond = Kilogram / Second;
+            KGPMOL: KilogramPerMole = Kilogram / Mole, MolarMass;
+            KGPS: KilogramPerSecond = Kilogram / Second, MassFlowRate;
             JPT: JoulePerTesla = Joule / Tesla;
             WPM3: WattPerMeter3 = Watt / Meter3;
             KPW: KelvinPerWatt = Kelvin / Watt;
@@ -143,32 +143,34 @@ Where experimental values are used for constants, the values are obtained from t
             RAD: Unitless = 1.0 * ONE.value_unsafe, "Radian";
             SR: Unitless = 1.0 * ONE.value_unsafe, "Steradian";
 
-            MIN: Second = 60.0 * S.value_unsafe, "Minute";
-            HR: Second = 60.0 * MIN.value_unsafe, "Hour";
-            DAY: Second = 24.0 * HR.value_unsafe, "Day";
+            MIN: Second = 60.0 * S.value_unsafe, "Minute", integer;
+            HR: Second = 60.0 * MIN.value_unsafe, "Hour", integer;
+            DAY: Second = 24.0 * HR.value_unsafe, "Day", integer;
 
             DEG: Unitless = consts::PI / 180.0 * RAD.value_unsafe, "Degree";
-            HA: Meter2 = 10000.0 * M2.value_unsafe, "Hectare";
+            KM: Meter = 1000.0 * M.value_unsafe, "Kilometer", integer;
+            HA: Meter2 = 10000.0 * M2.value_unsafe, "Hectare", integer;
             L: Meter3 = 0.001 * M3.value_unsafe, "Liter";
-            TNE: Kilogram = 1.0e3 * KG.value_unsafe, "Tonne";
-            AU: Meter = 149_597_870_700.0 * M.value_unsafe, "Astronomical unit";
+            G: Kilogram = 1.0e-3 * KG.value_unsafe, "Gram";
+            TNE: Kilogram = 1.0e3 * KG.value_unsafe, "Tonne", integer;
+            AU: Meter = 149_597_870_700.0 * M.value_unsafe, "Astronomical unit", integer(i64, u64);
 
             E: Coulomb = 1.6021766208e-19 * C.value_unsafe, "Elementary charge";
             EV: Joule = E.value_unsafe * V.value_unsafe, "Electronvolt";
             U: Kilogram = 1.660539040e-27 * KG.value_unsafe, "Unified atomic mass unit; dalton";
 
-            C0: MeterPerSecond = 299_792_458.0 * MPS.value_unsafe, "Speed of light in a vacuum";
+            C0: MeterPerSecond = 299_792_458.0 * MPS.value_unsafe, "Speed of light in a vacuum", integer;
 
             HBAR: JouleSecond = 1.054571800e-34 * JS.value_unsafe, "Reduced Planck constant";
             M_E: Kilogram = 9.10938356e-31 * KG.value_unsafe, "Electron mass";
             R_BOHR: Meter = 0.52917721067e-10 * M.value_unsafe, "Bohr radius";
             EH: Joule = 4.359744650e-18 * J.value_unsafe, "Hartree energy";
             AO: Meter = 1e-10 * M.value_unsafe, "Ångström";
-            ARE: Meter2 = 100.0 * M2.value_unsafe, "Are";
+            ARE: Meter2 = 100.0 * M2.value_unsafe, "Are", integer;
             BARN: Meter2 = 1e-28 * M2.value_unsafe, "Barn";
-            BAR: Pascal = 1e5 * PA.value_unsafe, "Bar";
-            MBAR: Pascal = 100.0 * PA.value_unsafe, "Millibar";
-            ATM: Pascal = 101_325.0 * PA.value_unsafe, "Atmosphere";
+            BAR: Pascal = 1e5 * PA.value_unsafe, "Bar", integer;
+            MBAR: Pascal = 100.0 * PA.value_unsafe, "Millibar", integer;
+            ATM: Pascal = 101_325.0 * PA.value_unsafe, "Atmosphere", integer;
             BA: Pascal = 0.1 * PA.value_unsafe, "Barye";
             MMHG: Pascal = 133.322387415 * PA.value_unsafe, "Millimeter of mercury";
             TORR: Pascal = ATM.value_unsafe / 760.0, "Torr";
@@ -184,9 +186,11 @@ Where experimental values are used for constants, the values are obtained from t
             OZ: Kilogram = LB.value_unsafe / 16.0, "Ounce";
 
             LBF: Newton = 4.4482216152605 * N.value_unsafe, "Pound force";
+            PSI: Pascal = LBF.value_unsafe / IN.value_unsafe / IN.value_unsafe, "Pound per square inch";
         ),
         fmt: true,
-        from: vec!["UCUM"],
+        from: vec!["UCUM", "FPS", "SIA"],
+        partial_from: vec!["SIA"],
         refl_blacklist: vec![
             "MOL",
             "KAT",
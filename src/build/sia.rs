@@ -0,0 +1,76 @@
+use super::*;
+
+/// The SI system, with the radian added as a base unit.
+///
+/// Everything else is taken from the SI system, so that the two stay in sync.
+pub fn new() -> System {
+    let si = si::new();
+
+    let mut base = si.base;
+    base.extend(base_units!(
+        RAD: Radian, rad;
+    ));
+
+    let mut derived = Vec::new();
+    for d in si.derived {
+        match d.constant {
+            "LM" => derived.extend(derived_units!(
+                SR: Steradian, "sr" = Radian * Radian;
+                LM: Lumen, "lm" = Candela * Steradian, LuminousFlux;
+            )),
+            "LX" => derived.extend(derived_units!(
+                LX: Lux, "lx" = Lumen / Meter2, Illuminance;
+            )),
+            // With angle as a dimension, these are no longer angular velocity, angular acceleration
+            // and torque.
+            "HZ" => derived.push(DerivedUnit {
+                dim: "Frequency",
+                ..d
+            }),
+            "PS2" => derived.push(DerivedUnit { dim: "", ..d }),
+            "J" => derived.push(DerivedUnit { dim: "Energy", ..d }),
+            _ => derived.push(d),
+        }
+    }
+    derived.extend(derived_units!(
+        RADPS: RadianPerSecond = Radian / Second, AngularVelocity;
+        RADPS2: RadianPerSecond2 = Radian / Second2, AngularAcceleration;
+        NMPRAD: NewtonMeterPerRadian = Newton * Meter / Radian, Torque;
+    ));
+
+    let mut constants = Vec::new();
+    for c in si.constants {
+        match c.constant {
+            "RAD" | "SR" => (),
+            "DEG" => constants.extend(constants!(
+                DEG: Radian = consts::PI / 180.0 * RAD.value_unsafe, "Degree";
+            )),
+            _ => constants.push(c),
+        }
+    }
+
+    System {
+        name: "SIA",
+        module: "sia",
+        doc_prelude: "The International System of Units (SI), with angle as a base dimension
+
+This system is identical to the `SI` system, except that it has the radian as an eighth base unit,
+in which the steradian, lumen, and lux are defined. So, an angular velocity has units of `rad/s`,
+rather than `1/s` like a frequency, and it is an error to add the two.
+
+It converts to and from the `UCUM` system, which also has the radian as a base unit, and so is the
+way to convert quantities with angles, such as angular velocity, between `UCUM` and SI units.
+However, as `UCUM` has no unit for amount of substance, converting to `UCUM` is only defined for
+units without moles. It also converts to and from the `SI` system, where converting to `SI`
+discards the radian. See the `conversion` module for details.
+
+",
+        base,
+        derived,
+        constants,
+        fmt: true,
+        from: vec!["SI", "UCUM"],
+        partial_from: Vec::new(),
+        refl_blacklist: si.refl_blacklist,
+    }
+}
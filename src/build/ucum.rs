@@ -24,10 +24,12 @@ There are a few classifications of units that UCUM defines but that it does not
 define here, and so we do not. They are as follows:
 
 * Units defined in terms of characters that we can't use. For example, the
-symbol for minutes as measure of angle is given by a single quote, '.
+  symbol for minutes as measure of angle is given by a single quote, '.
 
 * Units that require conversions that involve more than multiplication. These include some
-temperature units (such as degrees Celcius) and logrithmic units (such as decibels).
+  temperature units (such as degrees Celcius) and logrithmic units (such as decibels).
+  Temperatures on the Celsius and Fahrenheit scales are handled by the `temperature` module
+  instead, and levels in decibels, nepers and pH by the `logarithmic` module.
 
 ---
 
@@ -43,12 +45,12 @@ conflicts with coulomb, so we use `C_` for the speed of light.
 A note on derived units:
 
 Mass is involved in a great many units. As the SI unit of mass is the kilogram, there are many
-units defined in terms of tffhe kilogram. To create a derived unit in dimensioned, it must have a
-value of 1.0. Since UCUM uses the gram and not the kilogram as a base unit, that leads to many
-common derived units being off by a factor of 1000. As a result, you will see many derived units
-with prefixes, such as `MilliNewton` and `KiloFarad`. The constants for the more common units (`N`
-and `F` for the two mentioned) are still defined, but if you need to refer to their types (such as
-in function signatures), this is something to bear in mind.
+units defined in terms of the kilogram. Since UCUM uses the gram and not the kilogram as a base
+unit, many common derived units are off by a factor of 1000 from the units they are made of. Both
+are defined; for example, `N` is a `Newton` with a value of 1000 in base units, and `MILLIN` is a
+`MilliNewton` with a value of 1. As these have the same units, `Newton` and `MilliNewton` are the
+same type, and quantities of that type are printed in `mN`, so `2.0 * N` prints as `2000 mN`. The
+`fmt::Engineering` adaptor picks a prefix for the value, and prints it as `2 N`.
 
 ---
 ",
@@ -62,25 +64,37 @@ in function signatures), this is something to bear in mind.
             CD:  Candela,  cd,  LuminousIntensity;
         ),
         derived: derived_units!(
-            SR:       Steradian   = Radian * Radian;
-            HZ:       Hertz       = Unitless / Second, Frequency;
-            MILLIN:   MilliNewton = Gram * Meter / Second / Second, Force;
-            MILLIPA:  MilliPascal = MilliNewton / Meter / Meter, Pressure;
-            MILLIJ:   MilliJoule  = MilliNewton * Meter, Energy;
-            MILLIW:   MilliWatt   = MilliJoule / Second, Power;
-            A:        Ampere      = Coulomb / Second, Current;
-            MILLIV:   MilliVolt   = MilliJoule / Coulomb, ElectricPotential;
-            KILOF:    KiloFarad   = Coulomb / MilliVolt, Capacitance;
-            MILLIOHM: MilliOhm    = MilliVolt / Ampere, Resistance;
-            KILOSIE:  KiloSiemens = Unitless / MilliOhm, Conductance;
-            MILLIWB:  MilliWeber  = MilliVolt * Second, MagneticFlux;
-            MILLIT:   MilliTesla  = MilliWeber / Meter / Meter;
-            MILLIH:   MilliHenry  = MilliWeber / Ampere, Inductance;
-            LM:       Lumen       = Candela * Steradian;
-            LX:       Lux         = Lumen / Meter / Meter;
-            BQ:       Becquerel   = Unitless / Second;
-            GY:       Gray        = MilliJoule / Gram;
-            SV:       Sievert     = MilliJoule / Gram;
+            SR:       Steradian, "sr" = Radian * Radian;
+            HZ:       Hertz, "Hz" = Unitless / Second, Frequency;
+            MILLIN:   MilliNewton, "mN" = Gram * Meter / Second / Second, Force;
+            MILLIPA:  MilliPascal, "mPa" = MilliNewton / Meter / Meter, Pressure;
+            MILLIJ:   MilliJoule, "mJ" = MilliNewton * Meter, Energy;
+            MILLIW:   MilliWatt, "mW" = MilliJoule / Second, Power;
+            A:        Ampere, "A" = Coulomb / Second, Current;
+            MILLIV:   MilliVolt, "mV" = MilliJoule / Coulomb, ElectricPotential;
+            KILOF:    KiloFarad, "kF" = Coulomb / MilliVolt, Capacitance;
+            MILLIOHM: MilliOhm, "mΩ" = MilliVolt / Ampere, Resistance;
+            KILOSIE:  KiloSiemens, "kS" = Unitless / MilliOhm, Conductance;
+            MILLIWB:  MilliWeber, "mWb" = MilliVolt * Second, MagneticFlux;
+            MILLIT:   MilliTesla, "mT" = MilliWeber / Meter / Meter, MagneticFluxDensity;
+            MILLIH:   MilliHenry, "mH" = MilliWeber / Ampere, Inductance;
+            LM:       Lumen, "lm" = Candela * Steradian, LuminousFlux;
+            LX:       Lux, "lx" = Lumen / Meter / Meter, Illuminance;
+            BQ:       Becquerel = Unitless / Second, Radioactivity;
+            GY:       Gray, "Gy" = MilliJoule / Gram, AbsorbedDose;
+            SV:       Sievert = MilliJoule / Gram, EquivalentDose;
+
+            N:   Newton, "N" = (MilliNewton) * 1000.0;
+            PA:  Pascal, "Pa" = (MilliPascal) * 1000.0;
+            J:   Joule, "J" = (MilliJoule) * 1000.0;
+            W:   Watt, "W" = (MilliWatt) * 1000.0;
+            V:   Volt, "V" = (MilliVolt) * 1000.0;
+            F:   Farad, "F" = (KiloFarad) * 0.001;
+            OHM: Ohm, "Ω" = (MilliOhm) * 1000.0;
+            SIE: Siemens, "S" = (KiloSiemens) * 0.001;
+            WB:  Weber, "Wb" = (MilliWeber) * 1000.0;
+            T:   Tesla, "T" = (MilliTesla) * 1000.0;
+            H:   Henry, "H" = (MilliHenry) * 1000.0;
 
             // Miscellaneous useful type definitions:
             S2: Second2 = Second * Second;
@@ -94,21 +108,36 @@ in function signatures), this is something to bear in mind.
             MPS: MeterPerSecond = Meter / Second, Velocity;
             MPS2: MeterPerSecond2 = Meter / Second2, Acceleration;
             MPS3: MeterPerSecond3 = Meter / Second3, Jerk;
-            M2PS: Meter2PerSecond = Meter2 / Second;
+            M2PS: Meter2PerSecond = Meter2 / Second, KinematicViscosity;
             M2PS2: Meter2PerSecond2 = Meter2 / Second2;
+            M3PS: Meter3PerSecond = Meter3 / Second, VolumetricFlowRate;
 
-            APM: AmperePerMeter = Ampere / Meter;
-            CPM2: CandelaPerMeter2 = Candela / Meter2;
+            RADPS: RadianPerSecond = Radian / Second, AngularVelocity;
+            RADPS2: RadianPerSecond2 = Radian / Second2, AngularAcceleration;
+
+            APM: AmperePerMeter = Ampere / Meter, MagneticFieldStrength;
+            APM2: AmperePerMeter2 = Ampere / Meter2, CurrentDensity;
+            CPM2: CandelaPerMeter2 = Candela / Meter2, Luminance;
             CPG: CoulombPerGram = Coulomb / Gram;
             GPM: GramPerMeter = Gram / Meter;
+            GPM3: GramPerMeter3 = Gram / Meter3, Density;
+            GPS: GramPerSecond = Gram / Second, MassFlowRate;
             GPM4S: GramPerMeter4Second = Gram / Meter3 / Meter / Second;
 
             MILLIJS: MilliJouleSecond = MilliJoule * Second;
-            MILLIJPK: MilliJoulePerKelvin = MilliJoule / Kelvin;
-            KILOFPM: KiloFaradPerMeter = KiloFarad / Meter;
-            MILLINPA2: MilliNewtonPerAmpere2 = MilliNewton / Ampere / Ampere;
+            MILLIJPK: MilliJoulePerKelvin = MilliJoule / Kelvin, HeatCapacity, Entropy;
+            MILLIJPGK: MilliJoulePerGramKelvin = MilliJoulePerKelvin / Gram, SpecificHeatCapacity;
+            MILLIWPMK: MilliWattPerMeterKelvin = MilliWatt / Meter / Kelvin, ThermalConductivity;
+            MILLIWPM2: MilliWattPerMeter2 = MilliWatt / Meter2, HeatFluxDensity;
+            MILLINS: MilliNewtonSecond = MilliNewton * Second, Momentum;
+            MILLINPM: MilliNewtonPerMeter = MilliNewton / Meter, SurfaceTension;
+            MILLIJPRAD: MilliJoulePerRadian = MilliJoule / Radian, Torque;
+            KILOFPM: KiloFaradPerMeter = KiloFarad / Meter, Permittivity;
+            MILLINPA2: MilliNewtonPerAmpere2 = MilliNewton / Ampere / Ampere, Permeability;
+            MILLIVPM: MilliVoltPerMeter = MilliVolt / Meter, ElectricField;
+            KILOSIEPM: KiloSiemensPerMeter = KiloSiemens / Meter, ElectricalConductivity;
             M3PGS2: Meter3PerGramSecond2 = Meter3 / Gram / Second2;
-            MILLIPS: MilliPascalSecond = MilliPascal * Second;
+            MILLIPS: MilliPascalSecond = MilliPascal * Second, DynamicViscosity;
 
 
             M3PSG: Meter3PerSecondGram = Meter3 / Second / Gram;
@@ -120,31 +149,20 @@ in function signatures), this is something to bear in mind.
 
             // SI Units continued (UCUM Section 30):
             MOL: Unitless = 6.0221367e23*ONE.value_unsafe, "Mole";
-            N:   MilliNewton = KILO*MILLIN.value_unsafe, "Newton";
-            PA:  MilliPascal = KILO*MILLIPA.value_unsafe, "Pascal";
-            J:  MilliJoule = KILO*MILLIJ.value_unsafe, "Joule";
-            W:  MilliWatt = KILO*MILLIW.value_unsafe, "Watt";
-            V:  MilliVolt = KILO*MILLIV.value_unsafe, "Volt";
-            F:  KiloFarad = MILLI*KILOF.value_unsafe, "Farad";
-            OHM:  MilliOhm = KILO*MILLIOHM.value_unsafe, "Ohm";
-            SIE:  KiloSiemens = MILLI*KILOSIE.value_unsafe, "Siemens";
-            WB:  MilliWeber = KILO*MILLIWB.value_unsafe, "Weber";
-            T:  MilliTesla = KILO*MILLIT.value_unsafe, "Tesla";
-            H:  MilliHenry = KILO*MILLIH.value_unsafe, "Henry";
 
             // Units from ISO 1000, ISO 2955, and ANSI X3.50 (UCUM Section 31):
             GON: Radian = 0.9 * DEG.value_unsafe, "Gon, grade";
             DEG: Radian = 2.0 * consts::PI / 360.0 * RAD.value_unsafe, "Degree";
             L: Meter3 = 0.1 * 0.1 * 0.1 * M3.value_unsafe, "Liter";
             AR: Meter2 = 100.0 * M2.value_unsafe, "Are";
-            MIN: Second = 60.0 * S.value_unsafe, "Minute";
-            HR: Second = 60.0 * MIN.value_unsafe, "Hour";
-            D: Second = 24.0 * HR.value_unsafe, "Day";
+            MIN: Second = 60.0 * S.value_unsafe, "Minute", integer;
+            HR: Second = 60.0 * MIN.value_unsafe, "Hour", integer;
+            D: Second = 24.0 * HR.value_unsafe, "Day", integer;
             ANN_T: Second = 365.24219 * D.value_unsafe, "Tropical year";
             ANN_J: Second = 365.25 * D.value_unsafe, "Mean Julian year";
             ANN_G: Second = 365.2425 * D.value_unsafe, "Mean Gregorian year";
             ANN: Second = ANN_J.value_unsafe, "year";
-            WK: Second = 7.0 * D.value_unsafe, "week";
+            WK: Second = 7.0 * D.value_unsafe, "week", integer;
             MO_S: Second = 29.53059 * D.value_unsafe, "Synodal month";
             MO_J: Second = ANN_J.value_unsafe / 12.0, "Mean Julian month";
             MO_G: Second = ANN_G.value_unsafe / 12.0, "Mean Gregorian month";
@@ -370,7 +388,21 @@ in function signatures), this is something to bear in mind.
             SMOOT: Meter = 67.0 * IN_I.value_unsafe, "Smoot";
         ),
         fmt: true,
-        from: vec!["SI"],
-        refl_blacklist: vec!["RAD", "SR", "GON", "DEG", "CIRC", "LM", "SPH", "PHT", "LX"],
+        from: vec!["SI", "SIA"],
+        partial_from: Vec::new(),
+        refl_blacklist: vec![
+            "RAD",
+            "SR",
+            "GON",
+            "DEG",
+            "CIRC",
+            "LM",
+            "SPH",
+            "PHT",
+            "LX",
+            "RADPS",
+            "RADPS2",
+            "MILLIJPRAD",
+        ],
     }
 }
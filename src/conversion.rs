@@ -6,247 +6,530 @@
 //! Note that it does not always make sense to do so. For example, while one can convert from a
 //! subset of the `SI` system to `CGS`, it makes no sense to convert from `CGS` to `SI`.
 //!
-//! If are interested in implementing conversion for your own unit system, [here is an
-//! example](https://github.com/paholg/dimensioned-examples/blob/master/src/conversion.md)
-//! demonstrating how to do so.
+//! If are interested in implementing conversion for your own unit system, the `make_conversion!`
+//! macro creates the `From` implementation given how the base units of one system map onto those of
+//! the other. All of the conversions here are created with it.
+//!
+//! Conversions keep the value type of a quantity, so an `f32` quantity converts to an `f32`
+//! quantity. Those that scale the value are implemented with `From` for any value type `V` that
+//! implements `num_traits::NumCast`, `Mul<V, Output = V>` and `Div<V, Output = V>`, such as the
+//! floating point types or a dual number type, with the conversion factor cast to `V`. When a
+//! conversion factor is a whole number, or its reciprocal is, the value is multiplied or divided
+//! by that whole number. `From` panics if the value type can't represent the conversion factor.
+//!
+//! So integer quantities are better converted with the trait
+//! [`TryConvert`](trait.TryConvert.html), which fails rather than lose precision, as when
+//! converting an integer length from meters to feet, or 1500 grams to kilograms. Every conversion
+//! implements it, so it may also be used for those that don't scale the value, which implement
+//! `From` for all value types.
+//!
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::conversion::{InexactConversion, TryConvert};
+//! use dim::{fps, si, ucum};
+//!
+//! fn main() {
+//!     assert_eq!(si::Kilogram::from(1500.0 * ucum::G), 1.5 * si::KG);
+//!
+//!     let mass: Result<si::Kilogram<i32>, _> = ucum::Gram::new(3000).try_convert();
+//!     assert_eq!(mass, Ok(si::Kilogram::new(3)));
+//!
+//!     let mass: Result<si::Kilogram<i32>, _> = ucum::Gram::new(1500).try_convert();
+//!     assert_eq!(mass, Err(InexactConversion));
+//!
+//!     let length: Result<fps::Foot<i32>, _> = si::Meter::new(1).try_convert();
+//!     assert_eq!(length, Err(InexactConversion));
+//! }
+//! ```
+//!
+//! ```rust,should_panic
+//! # extern crate dimensioned as dim;
+//! # use dim::{fps, si};
+//! # fn main() {
+//! // panics: an `i32` can't represent the factor from meters to feet
+//! let length = fps::Foot::from(si::Meter::new(1));
+//! # }
+//! ```
 //!
 //! Conversions between unit systems are implemented as follows:
 //!
 //! * `SI` to `UCUM`: As `UCUM` does not have a unit for amount of substance, this is defined only
-//! for `SI` units that don't contain `Mole`s. In addition, as `UCUM` defines radians as a unit, it
-//! is not correct to perform this conversion if the quantity expresses an angle.
+//!   for `SI` units that don't contain `Mole`s. In addition, as `UCUM` defines radians as a unit,
+//!   it is not correct to perform this conversion if the quantity expresses an angle.
 //!
 //! * `SI` to `CGS` and `MKS`: These conversions are only defined for `SI` units that are a
-//! combination of `Meter`, `Kilogram`, `Second`, and `Ampere`.
+//!   combination of `Meter`, `Kilogram`, `Second`, and `Ampere`.
 //!
 //! * `UCUM` to `SI`: As `SI` does not have a unit for angle, this is only defined for `UCUM`
-//! units that don't contain `Radian`s.
+//!   units that don't contain `Radian`s.
+//!
+//! * `SI` to `SIA`: As `SI` does not have a unit for angle, the result never contains `Radian`s.
+//!
+//! * `SIA` to `SI`: Any `Radian`s are discarded, as they are dimensionless in `SI`.
+//!
+//! * `SIA` to `UCUM`: As `UCUM` does not have a unit for amount of substance, this is defined only
+//!   for `SIA` units that don't contain `Mole`s.
+//!
+//! * `UCUM` to `SIA`: Unlike converting to `SI`, this is defined for units with angles, such as
+//!   angular velocity, so it is the way to convert such quantities between `UCUM` and SI units.
+//!
+//! * `SI` to `FPS`: This conversion is only defined for `SI` units that are a combination of
+//!   `Meter`, `Kilogram`, and `Second`.
+//!
+//! * `FPS` to `SI`: As `SI` has no square root units, this is only defined for `FPS` units with
+//!   even powers of `SqrtFoot` and `SqrtPound`.
 //!
 //! * `CGS` to `MKS`
 //! * `MKS` to `CGS`
+//! * `MKS` to `FPS`
+//! * `FPS` to `MKS`
 
-mod ucum_to_si {
-    // From UCUM
-    use crate::f64prefixes::*;
-    use crate::si::SI;
-    use crate::ucum;
-    use core::convert::From;
-    use core::ops::{Add, Mul};
-    use num_traits::float::FloatCore;
-    use typenum::{Integer, Prod, Sum, Z0};
-
-    impl<V, Meter, Second, Gram, Kelvin, Coulomb, Candela>
-        From<ucum::UCUM<V, tarr![Meter, Second, Gram, Z0, Kelvin, Coulomb, Candela]>>
-        for SI<Prod<V, f64>, tarr![Meter, Gram, Sum<Second, Coulomb>, Coulomb, Kelvin, Candela, Z0]>
-    where
-        Meter: Integer,
-        Second: Integer + Add<Coulomb>,
-        Gram: Integer,
-        Kelvin: Integer,
-        Coulomb: Integer,
-        Candela: Integer,
-        V: Mul<f64>,
-    {
-        fn from(
-            other: ucum::UCUM<V, tarr![Meter, Second, Gram, Z0, Kelvin, Coulomb, Candela]>,
-        ) -> Self {
-            let gfac = FloatCore::powi(MILLI, Gram::to_i32());
-
-            let fac = gfac;
-
-            SI::new(other.value_unsafe * fac)
-        }
-    }
+use crate::f64prefixes::{CENTI, HECTO, KILO, MILLI};
+use crate::{cgs, fps, mks, si, sia, ucum};
+use core::fmt;
+use core::ops::{Div, Mul};
+use num_traits::float::FloatCore;
+use num_traits::PrimInt;
+
+// The foot and the pound, in meters and kilograms.
+const FOOT: f64 = 0.3048;
+const POUND: f64 = 0.453_592_37;
+
+// Square roots of conversion factors, for units with odd powers of square root base units. These
+// are precomputed so that conversions don't need `std`.
+const SQRT_HECTO: f64 = 10.0;
+const SQRT_KILO: f64 = 31.622_776_601_683_793;
+const SQRT_CENTI: f64 = 0.1;
+const SQRT_MILLI: f64 = 0.031_622_776_601_683_79;
+const SQRT_FOOT: f64 = 0.552_086_949_673_690_4;
+const SQRT_POUND: f64 = 0.673_492_665_141_945;
+
+/// Convert quantities between unit systems, failing if the value can't be converted exactly.
+///
+/// This is implemented wherever `From` is: for all value types when the conversion doesn't scale
+/// the value, and only for primitive integers otherwise. See the
+/// [module documentation](index.html).
+pub trait TryConvert<T> {
+    /// Convert `self` to `T`.
+    fn try_convert(self) -> Result<T, InexactConversion>;
 }
 
-mod si_to_ucum {
-    // From SI
-    use crate::f64prefixes::*;
-    use crate::si;
-    use crate::ucum::UCUM;
-    use core::convert::From;
-    use core::ops::{Mul, Sub};
-    use num_traits::float::FloatCore;
-    use typenum::{Diff, Integer, Prod, Z0};
-
-    impl<V, Meter, Kilogram, Second, Ampere, Kelvin, Candela>
-        From<si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Kelvin, Candela, Z0]>>
-        for UCUM<
-            Prod<V, f64>,
-            tarr![Meter, Diff<Second, Ampere>, Kilogram, Z0, Kelvin, Ampere, Candela],
-        >
-    where
-        Meter: Integer,
-        Kilogram: Integer,
-        Second: Integer + Sub<Ampere>,
-        Ampere: Integer,
-        Kelvin: Integer,
-        Candela: Integer,
-        V: Mul<f64>,
-    {
-        fn from(
-            other: si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Kelvin, Candela, Z0]>,
-        ) -> Self {
-            let kgfac = FloatCore::powi(KILO, Kilogram::to_i32());
-
-            let fac = kgfac;
-
-            UCUM::new(other.value_unsafe * fac)
-        }
+/// The error returned when a quantity can't be converted exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InexactConversion;
+
+impl fmt::Display for InexactConversion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "quantity can't be converted exactly")
     }
 }
 
-#[cfg(any(feature = "std", feature = "nightly"))]
-mod mks_to_cgs {
-    use crate::cgs::CGS;
-
-    // From MKS
-    use crate::f64prefixes::*;
-    use crate::mks;
-    use crate::traits::Sqrt;
-    use core::convert::From;
-    use core::ops::Mul;
-    use num_traits::float::FloatCore;
-    use typenum::{Integer, Prod};
-
-    impl<V, SqrtMeter, SqrtKilogram, Second>
-        From<mks::MKS<V, tarr![SqrtMeter, SqrtKilogram, Second]>>
-        for CGS<Prod<V, f64>, tarr![SqrtMeter, SqrtKilogram, Second]>
-    where
-        SqrtMeter: Integer,
-        SqrtKilogram: Integer,
-        Second: Integer,
-        V: Mul<f64>,
-    {
-        fn from(other: mks::MKS<V, tarr![SqrtMeter, SqrtKilogram, Second]>) -> Self {
-            let mfac = match SqrtMeter::to_i32() {
-                e if e % 2 == 0 => FloatCore::powi(HECTO, e / 2),
-                e => FloatCore::powi(Sqrt::sqrt(HECTO), e),
-            };
-            let kgfac = match SqrtKilogram::to_i32() {
-                e if e % 2 == 0 => FloatCore::powi(KILO, e / 2),
-                e => FloatCore::powi(Sqrt::sqrt(KILO), e),
-            };
-
-            let fac = mfac * kgfac;
-
-            CGS::new(other.value_unsafe * fac)
-        }
+#[cfg(feature = "std")]
+impl std::error::Error for InexactConversion {}
+
+/// Multiply `value` by `factor`, in the value type of `value`.
+///
+/// When `factor` is a whole number, or its reciprocal is, `value` is multiplied or divided by that
+/// whole number.
+#[doc(hidden)]
+pub fn scale<V>(value: V, factor: f64) -> V
+where
+    V: num_traits::NumCast + Mul<Output = V> + Div<Output = V>,
+{
+    let recip = 1.0 / factor;
+    if FloatCore::fract(factor) != 0.0 && FloatCore::fract(recip) == 0.0 {
+        value / cast(recip)
+    } else {
+        value * cast(factor)
     }
 }
 
-#[cfg(any(feature = "std", feature = "nightly"))]
-mod si_to_cgs {
-    use crate::cgs::CGS;
-
-    use crate::mks;
-    use core::convert::From;
-    use core::ops::{Add, Mul};
-    use typenum::{Integer, Prod, Sum};
-
-    // From SI
-    use crate::si;
-    use typenum::{P2, P3, Z0};
-
-    impl<V, Meter, Kilogram, Second, Ampere>
-        From<si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Z0, Z0, Z0]>>
-        for CGS<
-            Prod<Prod<V, f64>, f64>,
-            tarr![
-                Sum<Prod<Meter, P2>, Prod<Ampere, P3>>,
-                Sum<Prod<Kilogram, P2>, Ampere>,
-                Sum<Second, Prod<Ampere, P2>>
-            ],
-        >
-    where
-        V: Mul<f64>,
-        Meter: Integer + Mul<P2>,
-        Kilogram: Integer + Mul<P2>,
-        Second: Integer + Add<Prod<Ampere, P2>>,
-        Ampere: Integer + Mul<P2> + Mul<P3>,
-        Prod<Meter, P2>: Add<Prod<Ampere, P3>>,
-        Prod<Kilogram, P2>: Add<Ampere>,
-        Sum<Prod<Meter, P2>, Prod<Ampere, P3>>: Integer,
-        Sum<Prod<Kilogram, P2>, Ampere>: Integer,
-        Sum<Second, Prod<Ampere, P2>>: Integer,
-        Prod<V, f64>: Mul<f64>,
-    {
-        fn from(other: si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Z0, Z0, Z0]>) -> Self {
-            CGS::from(mks::MKS::from(other))
-        }
+/// Convert `factor` to the value type `V`, panicking if that loses more than the precision of an
+/// `f32`.
+fn cast<V: num_traits::NumCast>(factor: f64) -> V {
+    V::from(factor)
+        .filter(|v| {
+            v.to_f64().is_some_and(|v| {
+                FloatCore::abs(v - factor) <= FloatCore::abs(factor) * f64::from(f32::EPSILON)
+            })
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "the conversion factor {} can't be represented by the value type",
+                factor
+            )
+        })
+}
+
+/// Multiply `value` by `factor`, failing unless `factor` or its reciprocal is a whole number that
+/// fits in `V`, and the result is exact.
+#[doc(hidden)]
+pub fn try_scale<V: PrimInt>(value: V, factor: f64) -> Result<V, InexactConversion> {
+    let recip = 1.0 / factor;
+    if FloatCore::fract(factor) == 0.0 {
+        V::from(factor).and_then(|factor| value.checked_mul(&factor))
+    } else if FloatCore::fract(recip) == 0.0 {
+        V::from(recip)
+            .filter(|&recip| (value % recip).is_zero())
+            .map(|recip| value / recip)
+    } else {
+        None
     }
+    .ok_or(InexactConversion)
 }
 
-#[cfg(any(feature = "std", feature = "nightly"))]
-mod cgs_to_mks {
-    use crate::f64prefixes::*;
-    use crate::mks::MKS;
-    use crate::traits::Sqrt;
-    use core::convert::From;
-    use core::ops::Mul;
-    use num_traits::float::FloatCore;
-    use typenum::{Integer, Prod};
-
-    // From CGS
-    use crate::cgs;
-
-    impl<V, SqrtCentimeter, SqrtGram, Second>
-        From<cgs::CGS<V, tarr![SqrtCentimeter, SqrtGram, Second]>>
-        for MKS<Prod<V, f64>, tarr![SqrtCentimeter, SqrtGram, Second]>
-    where
-        SqrtCentimeter: Integer,
-        SqrtGram: Integer,
-        Second: Integer,
-        V: Mul<f64>,
-    {
-        fn from(other: cgs::CGS<V, tarr![SqrtCentimeter, SqrtGram, Second]>) -> Self {
-            let cmfac = match SqrtCentimeter::to_i32() {
-                e if e % 2 == 0 => FloatCore::powi(CENTI, e / 2),
-                e => FloatCore::powi(Sqrt::sqrt(CENTI), e),
-            };
-            let gfac = match SqrtGram::to_i32() {
-                e if e % 2 == 0 => FloatCore::powi(MILLI, e / 2),
-                e => FloatCore::powi(Sqrt::sqrt(MILLI), e),
-            };
-
-            let fac = cmfac * gfac;
-
-            MKS::new(other.value_unsafe * fac)
-        }
+/// The conversion factor for a base unit with power `exp`, where `factor` is its factor.
+#[doc(hidden)]
+pub fn factor(factor: f64, exp: i32) -> f64 {
+    FloatCore::powi(factor, exp)
+}
+
+/// The conversion factor for a base unit with power `exp`, where it is the square root of a unit
+/// with factor `factor`, and `sqrt_factor` is the square root of `factor`.
+#[doc(hidden)]
+pub fn half_factor(factor: f64, sqrt_factor: f64, exp: i32) -> f64 {
+    if exp % 2 == 0 {
+        FloatCore::powi(factor, exp / 2)
+    } else {
+        FloatCore::powi(sqrt_factor, exp)
     }
 }
 
-mod si_to_mks {
-    use crate::mks::MKS;
-    use core::convert::From;
-    use core::ops::{Add, Mul};
-    use typenum::{Integer, Prod, Sum};
-
-    // From SI
-    use crate::si;
-    use typenum::{P2, P3, Z0};
-
-    impl<V, Meter, Kilogram, Second, Ampere>
-        From<si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Z0, Z0, Z0]>>
-        for MKS<
-            Prod<V, f64>,
-            tarr![
-                Sum<Prod<Meter, P2>, Prod<Ampere, P3>>,
-                Sum<Prod<Kilogram, P2>, Ampere>,
-                Sum<Second, Prod<Ampere, P2>>
-            ],
-        >
-    where
-        V: Mul<f64>,
-        Meter: Integer + Mul<P2>,
-        Kilogram: Integer + Mul<P2>,
-        Second: Integer + Add<Prod<Ampere, P2>>,
-        Ampere: Integer + Mul<P2> + Mul<P3>,
-        Prod<Meter, P2>: Add<Prod<Ampere, P3>>,
-        Prod<Kilogram, P2>: Add<Ampere>,
-    {
-        fn from(other: si::SI<V, tarr![Meter, Kilogram, Second, Ampere, Z0, Z0, Z0]>) -> Self {
-            MKS::new(other.value_unsafe * 1.0)
+/// Create a conversion between two unit systems
+///
+/// This implements `From` to convert quantities from one unit system to another, given the base
+/// units of each. It is used as follows:
+///
+/// ```rust,ignore
+/// make_conversion! {
+///     from_module::FromSystem[A, B, 0] => to_module::ToSystem[A * P2 + B, 0, B / P2];
+///     A: FACTOR_A;
+///     B: FACTOR_OF_SQUARE, SQRT_FACTOR;
+/// }
+/// ```
+///
+/// The first list has an entry for each base unit of the system being converted from, in order.
+/// Each entry is either a name, which stands for its power, or `0`, in which case the conversion
+/// is only defined for units without that base unit.
+///
+/// The second list has an entry for each base unit of the system being converted to, giving its
+/// power in terms of those names. It may be `0`, or a sum or difference of terms, where each term
+/// is a name, optionally multiplied or exactly divided by a type number from `typenum::consts`,
+/// such as `P2`.
+///
+/// Then, there may be a conversion factor for each name. The value of the converted quantity is
+/// multiplied by the factor raised to the power of that name, so for a conversion from meters to
+/// centimeters, the factor would be `100.0`. If the base unit is the square root of another unit,
+/// give the factor of that unit and its square root. Names without a factor aren't scaled.
+///
+/// The conversion is implemented with `From` for all value types when there are no factors, and
+/// for value types that implement `num_traits::NumCast`, `Mul` and `Div` otherwise. It is also implemented with `conversion::TryConvert`,
+/// which handles primitive integers when there are factors; see the
+/// [conversion module](conversion/index.html) for details.
+///
+/// As with `make_units!`, this macro requires `tarr!` to be in scope, and the systems must be in
+/// a crate other than the one defining the conversion, or the conversion must be in the crate
+/// defining one of the systems.
+///
+/// # Example
+///
+/// ```rust
+/// # #![cfg_attr(feature = "oibit", feature(auto_traits))]
+/// # #![cfg_attr(feature = "oibit", feature(negative_impls))]
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// mod ms {
+///     make_units! {
+///         MS;
+///         ONE: Unitless;
+///         base {
+///             M: Meter, "m", Length;
+///             S: Second, "s", Time;
+///         }
+///         derived {
+///             MPS: MeterPerSecond = (Meter / Second), Velocity;
+///         }
+///         constants {}
+///         fmt = true;
+///     }
+///     pub use self::f64consts::*;
+/// }
+///
+/// mod minkm {
+///     make_units! {
+///         MINKM;
+///         ONE: Unitless;
+///         base {
+///             KM: Kilometer, "km", Length;
+///             MIN: Minute, "min", Time;
+///         }
+///         derived {}
+///         constants {}
+///         fmt = true;
+///     }
+///     pub use self::f64consts::*;
+/// }
+///
+/// make_conversion! {
+///     ms::MS[Meter, Second] => minkm::MINKM[Meter, Second];
+///     Meter: 0.001;
+///     Second: 1.0 / 60.0;
+/// }
+///
+/// fn main() {
+///     let v = minkm::MINKM::from(5.0 * ms::MPS);
+///     assert_eq!(v, 0.3 * minkm::KM / minkm::MIN);
+///
+///     use dim::conversion::TryConvert;
+///     let t: Result<minkm::Minute<i32>, _> = ms::Second::new(180).try_convert();
+///     assert_eq!(t, Ok(minkm::Minute::new(3)));
+///     let t: Result<minkm::Minute<i32>, _> = ms::Second::new(150).try_convert();
+///     assert!(t.is_err());
+/// }
+/// ```
+#[macro_export]
+macro_rules! make_conversion {
+    (
+        $($From:ident)::+ [$($src:tt),+ $(,)?] => $($To:ident)::+ [$($dst:tt)*];
+        $($factors:tt)*
+    ) => (
+        __make_conversion_internal!(
+            @src ([$($From)::+] [$($To)::+] [$($factors)*]) [$($dst)*] [] [] $($src,)+
+        );
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __make_conversion_internal {
+    // Read the base units of the system we're converting from, as generic parameters and types.
+    (@src $ctx:tt $dst:tt [$($p:ident)*] [$($t:ty,)*] 0, $($rest:tt)*) => (
+        __make_conversion_internal!(
+            @src $ctx $dst [$($p)*] [$($t,)* $crate::typenum::Z0,] $($rest)*
+        );
+    );
+    (@src $ctx:tt $dst:tt [$($p:ident)*] [$($t:ty,)*] $q:ident, $($rest:tt)*) => (
+        __make_conversion_internal!(@src $ctx $dst [$($p)* $q] [$($t,)* $q,] $($rest)*);
+    );
+    (@src ($($ctx:tt)*) [$($dst:tt)*] $params:tt $srcs:tt) => (
+        __make_conversion_internal!(@dst ($($ctx)* $params $srcs) [] [] $($dst)*,);
+    );
+
+    // Read the powers of the base units of the system we're converting to, collecting the bounds
+    // that they need.
+    (@dst $ctx:tt $done:tt $bounds:tt $(,)?) => (
+        __make_conversion_internal!(@impl $ctx $done $bounds);
+    );
+    (@dst $ctx:tt [$($done:tt)*] $bounds:tt 0, $($rest:tt)*) => (
+        __make_conversion_internal!(
+            @dst $ctx [$($done)* ($crate::typenum::Z0)] $bounds $($rest)*
+        );
+    );
+    (@dst $ctx:tt $done:tt $bounds:tt $($rest:tt)+) => (
+        __make_conversion_internal!(@term $ctx $done $bounds [first] $($rest)+);
+    );
+
+    (@term $ctx:tt $done:tt [$($b:tt)*] $op:tt $u:ident * $c:ident $($rest:tt)*) => (
+        __make_conversion_internal!(
+            @combine $ctx $done
+            [$($b)* $u: $crate::dimcore::ops::Mul<$crate::typenum::consts::$c>,]
+            $op ($crate::typenum::Prod<$u, $crate::typenum::consts::$c>) $($rest)*
+        );
+    );
+    (@term $ctx:tt $done:tt [$($b:tt)*] $op:tt $u:ident / $c:ident $($rest:tt)*) => (
+        __make_conversion_internal!(
+            @combine $ctx $done
+            [$($b)* $u: $crate::typenum::PartialDiv<$crate::typenum::consts::$c>,]
+            $op ($crate::typenum::PartialQuot<$u, $crate::typenum::consts::$c>) $($rest)*
+        );
+    );
+    (@term $ctx:tt $done:tt $bounds:tt $op:tt $u:ident $($rest:tt)*) => (
+        __make_conversion_internal!(@combine $ctx $done $bounds $op ($u) $($rest)*);
+    );
+
+    (@combine $ctx:tt $done:tt $bounds:tt [first] $term:tt $($rest:tt)*) => (
+        __make_conversion_internal!(@next $ctx $done $bounds $term $($rest)*);
+    );
+    (@combine $ctx:tt $done:tt [$($b:tt)*] [$Op:ident $Alias:ident ($($acc:tt)*)]
+     ($($term:tt)*) $($rest:tt)*) => (
+        __make_conversion_internal!(
+            @next $ctx $done
+            [$($b)* $($acc)*: $crate::dimcore::ops::$Op<$($term)*>,]
+            ($crate::typenum::$Alias<$($acc)*, $($term)*>) $($rest)*
+        );
+    );
+
+    (@next $ctx:tt $done:tt $bounds:tt $acc:tt + $($rest:tt)*) => (
+        __make_conversion_internal!(@term $ctx $done $bounds [Add Sum $acc] $($rest)*);
+    );
+    (@next $ctx:tt $done:tt $bounds:tt $acc:tt - $($rest:tt)*) => (
+        __make_conversion_internal!(@term $ctx $done $bounds [Sub Diff $acc] $($rest)*);
+    );
+    (@next $ctx:tt [$($done:tt)*] $bounds:tt $acc:tt , $($rest:tt)*) => (
+        __make_conversion_internal!(@dst $ctx [$($done)* $acc] $bounds $($rest)*);
+    );
+
+    // Create the implementation.
+    (@impl ([$($From:ident)::+] [$($To:ident)::+] [] [$($p:ident)*] [$($s:ty,)*])
+     [$(($($d:tt)*))*] [$($b:tt)*]) => (
+        impl<V, $($p),*> $crate::dimcore::convert::From<$($From)::+<V, tarr![$($s),*]>>
+            for $($To)::+<V, tarr![$($($d)*),*]>
+        where
+            $($p: $crate::typenum::Integer,)*
+            $($b)*
+        {
+            fn from(other: $($From)::+<V, tarr![$($s),*]>) -> Self {
+                Self::new(other.value_unsafe)
+            }
         }
-    }
+
+        impl<V, $($p),*> $crate::conversion::TryConvert<$($To)::+<V, tarr![$($($d)*),*]>>
+            for $($From)::+<V, tarr![$($s),*]>
+        where
+            $($p: $crate::typenum::Integer,)*
+            $($b)*
+        {
+            fn try_convert(self) -> Result<
+                $($To)::+<V, tarr![$($($d)*),*]>,
+                $crate::conversion::InexactConversion,
+            > {
+                Ok($($To)::+::new(self.value_unsafe))
+            }
+        }
+    );
+    (@impl ([$($From:ident)::+] [$($To:ident)::+] [$($factors:tt)+] [$($p:ident)*] [$($s:ty,)*])
+     [$(($($d:tt)*))*] [$($b:tt)*]) => (
+        impl<V, $($p),*> $crate::dimcore::convert::From<$($From)::+<V, tarr![$($s),*]>>
+            for $($To)::+<V, tarr![$($($d)*),*]>
+        where
+            V: $crate::num_traits::NumCast
+                + $crate::dimcore::ops::Mul<V, Output = V>
+                + $crate::dimcore::ops::Div<V, Output = V>,
+            $($p: $crate::typenum::Integer,)*
+            $($b)*
+        {
+            fn from(other: $($From)::+<V, tarr![$($s),*]>) -> Self {
+                let fac = __make_conversion_internal!(@factor $($factors)+);
+                Self::new($crate::conversion::scale(other.value_unsafe, fac))
+            }
+        }
+
+        impl<V, $($p),*> $crate::conversion::TryConvert<$($To)::+<V, tarr![$($($d)*),*]>>
+            for $($From)::+<V, tarr![$($s),*]>
+        where
+            V: $crate::num_traits::PrimInt,
+            $($p: $crate::typenum::Integer,)*
+            $($b)*
+        {
+            fn try_convert(self) -> Result<
+                $($To)::+<V, tarr![$($($d)*),*]>,
+                $crate::conversion::InexactConversion,
+            > {
+                let fac = __make_conversion_internal!(@factor $($factors)+);
+                $crate::conversion::try_scale(self.value_unsafe, fac).map($($To)::+::new)
+            }
+        }
+    );
+
+    // The product of the conversion factors.
+    (@factor) => (1.0);
+    (@factor $u:ident: $f:expr, $sqrt:expr; $($rest:tt)*) => (
+        $crate::conversion::half_factor($f, $sqrt, <$u as $crate::typenum::Integer>::to_i32())
+            * __make_conversion_internal!(@factor $($rest)*)
+    );
+    (@factor $u:ident: $f:expr; $($rest:tt)*) => (
+        $crate::conversion::factor($f, <$u as $crate::typenum::Integer>::to_i32())
+            * __make_conversion_internal!(@factor $($rest)*)
+    );
+}
+
+// From UCUM
+make_conversion! {
+    ucum::UCUM[Meter, Second, Gram, 0, Kelvin, Coulomb, Candela]
+        => si::SI[Meter, Gram, Second + Coulomb, Coulomb, Kelvin, Candela, 0];
+    Gram: MILLI;
+}
+
+make_conversion! {
+    ucum::UCUM[Meter, Second, Gram, Radian, Kelvin, Coulomb, Candela]
+        => sia::SIA[Meter, Gram, Second + Coulomb, Coulomb, Kelvin, Candela, 0, Radian];
+    Gram: MILLI;
+}
+
+// From SI
+make_conversion! {
+    si::SI[Meter, Kilogram, Second, Ampere, Kelvin, Candela, 0]
+        => ucum::UCUM[Meter, Second - Ampere, Kilogram, 0, Kelvin, Ampere, Candela];
+    Kilogram: KILO;
+}
+
+make_conversion! {
+    si::SI[Meter, Kilogram, Second, Ampere, Kelvin, Candela, Mole]
+        => sia::SIA[Meter, Kilogram, Second, Ampere, Kelvin, Candela, Mole, 0];
+}
+
+make_conversion! {
+    si::SI[Meter, Kilogram, Second, Ampere, 0, 0, 0]
+        => mks::MKS[Meter * P2 + Ampere * P3, Kilogram * P2 + Ampere, Second + Ampere * P2];
+}
+
+make_conversion! {
+    si::SI[Meter, Kilogram, Second, Ampere, 0, 0, 0]
+        => cgs::CGS[Meter * P2 + Ampere * P3, Kilogram * P2 + Ampere, Second + Ampere * P2];
+    Meter: HECTO;
+    Kilogram: KILO;
+    Ampere: KILO * SQRT_KILO;
+}
+
+make_conversion! {
+    si::SI[Meter, Kilogram, Second, 0, 0, 0, 0] => fps::FPS[Meter * P2, Kilogram * P2, Second];
+    Meter: 1.0 / FOOT;
+    Kilogram: 1.0 / POUND;
+}
+
+// From SIA
+make_conversion! {
+    sia::SIA[Meter, Kilogram, Second, Ampere, Kelvin, Candela, 0, Radian]
+        => ucum::UCUM[Meter, Second - Ampere, Kilogram, Radian, Kelvin, Ampere, Candela];
+    Kilogram: KILO;
+}
+
+make_conversion! {
+    sia::SIA[Meter, Kilogram, Second, Ampere, Kelvin, Candela, Mole, Radian]
+        => si::SI[Meter, Kilogram, Second, Ampere, Kelvin, Candela, Mole];
+}
+
+// From MKS
+make_conversion! {
+    mks::MKS[SqrtMeter, SqrtKilogram, Second] => cgs::CGS[SqrtMeter, SqrtKilogram, Second];
+    SqrtMeter: HECTO, SQRT_HECTO;
+    SqrtKilogram: KILO, SQRT_KILO;
+}
+
+make_conversion! {
+    mks::MKS[SqrtMeter, SqrtKilogram, Second] => fps::FPS[SqrtMeter, SqrtKilogram, Second];
+    SqrtMeter: 1.0 / FOOT, 1.0 / SQRT_FOOT;
+    SqrtKilogram: 1.0 / POUND, 1.0 / SQRT_POUND;
+}
+
+// From CGS
+make_conversion! {
+    cgs::CGS[SqrtCentimeter, SqrtGram, Second] => mks::MKS[SqrtCentimeter, SqrtGram, Second];
+    SqrtCentimeter: CENTI, SQRT_CENTI;
+    SqrtGram: MILLI, SQRT_MILLI;
+}
+
+// From FPS
+make_conversion! {
+    fps::FPS[SqrtFoot, SqrtPound, Second] => si::SI[SqrtFoot / P2, SqrtPound / P2, Second, 0, 0, 0, 0];
+    SqrtFoot: FOOT, SQRT_FOOT;
+    SqrtPound: POUND, SQRT_POUND;
+}
+
+make_conversion! {
+    fps::FPS[SqrtFoot, SqrtPound, Second] => mks::MKS[SqrtFoot, SqrtPound, Second];
+    SqrtFoot: FOOT, SQRT_FOOT;
+    SqrtPound: POUND, SQRT_POUND;
 }
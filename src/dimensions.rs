@@ -31,45 +31,131 @@
 //!     let v = speed(x, t);
 //!
 //!     assert_eq!(v, x / t);
-//!
-//!     // Compiler error:
-//!     // speed(x, x);
 //! }
 //! ```
+//!
+//! A quantity of the wrong dimension is rejected, with an error saying which dimension it lacks:
+//!
+//! ```rust,compile_fail,E0277
+//! # extern crate dimensioned as dim;
+//! # use dim::dimensions::{Length, Time};
+//! # use dim::typenum::Quot;
+//! # use std::ops::Div;
+//! # fn speed<L, T>(dist: L, time: T) -> Quot<L, T>
+//! # where
+//! #     L: Length + Div<T>,
+//! #     T: Time,
+//! # {
+//! #     dist / time
+//! # }
+//! # fn main() {
+//! use dim::si;
+//!
+//! let x = 3.0 * si::M;
+//! // error: `SI<f64, ...>` is not a time
+//! speed(x, x);
+//! # }
+//! ```
 
 #![allow(missing_docs)]
 
 use crate::Dimensioned;
 
-pub trait Length: Dimensioned {}
-pub trait ReciprocalLength: Dimensioned {}
-pub trait Area: Dimensioned {}
-pub trait Volume: Dimensioned {}
+/// Define a dimension trait, with the error the compiler gives for a type that doesn't implement it.
+macro_rules! dimension {
+    ($Trait:ident, $message:literal) => {
+        #[cfg_attr(
+            diagnostic_namespace,
+            diagnostic::on_unimplemented(
+                message = $message,
+                note = "the dimension traits are implemented for the units of each unit system that have that dimension"
+            )
+        )]
+        pub trait $Trait: Dimensioned {}
+    };
+}
+
+dimension!(Length, "`{Self}` is not a length");
+dimension!(ReciprocalLength, "`{Self}` is not a reciprocal length");
+dimension!(Area, "`{Self}` is not an area");
+dimension!(Volume, "`{Self}` is not a volume");
+
+dimension!(Mass, "`{Self}` is not a mass");
+dimension!(Time, "`{Self}` is not a time");
+dimension!(Temperature, "`{Self}` is not a temperature");
+
+dimension!(LuminousIntensity, "`{Self}` is not a luminous intensity");
+dimension!(LuminousFlux, "`{Self}` is not a luminous flux");
+dimension!(Illuminance, "`{Self}` is not an illuminance");
+dimension!(Luminance, "`{Self}` is not a luminance");
+
+dimension!(Velocity, "`{Self}` is not a velocity");
+dimension!(Acceleration, "`{Self}` is not an acceleration");
+dimension!(Jerk, "`{Self}` is not a jerk");
+dimension!(AngularVelocity, "`{Self}` is not an angular velocity");
+dimension!(
+    AngularAcceleration,
+    "`{Self}` is not an angular acceleration"
+);
 
-pub trait Mass: Dimensioned {}
-pub trait Time: Dimensioned {}
-pub trait Temperature: Dimensioned {}
+dimension!(Charge, "`{Self}` is not a charge");
+dimension!(Current, "`{Self}` is not a current");
+dimension!(ElectricPotential, "`{Self}` is not an electric potential");
+dimension!(Capacitance, "`{Self}` is not a capacitance");
+dimension!(Resistance, "`{Self}` is not a resistance");
+dimension!(Conductance, "`{Self}` is not a conductance");
+dimension!(MagneticFlux, "`{Self}` is not a magnetic flux");
+dimension!(
+    MagneticFluxDensity,
+    "`{Self}` is not a magnetic flux density"
+);
+dimension!(Inductance, "`{Self}` is not an inductance");
+dimension!(ElectricField, "`{Self}` is not an electric field");
+dimension!(
+    MagneticFieldStrength,
+    "`{Self}` is not a magnetic field strength"
+);
+dimension!(CurrentDensity, "`{Self}` is not a current density");
+dimension!(Permittivity, "`{Self}` is not a permittivity");
+dimension!(Permeability, "`{Self}` is not a permeability");
+dimension!(
+    ElectricalConductivity,
+    "`{Self}` is not an electrical conductivity"
+);
 
-pub trait LuminousIntensity: Dimensioned {}
+dimension!(Frequency, "`{Self}` is not a frequency");
 
-pub trait Velocity: Dimensioned {}
-pub trait Acceleration: Dimensioned {}
-pub trait Jerk: Dimensioned {}
+dimension!(Force, "`{Self}` is not a force");
+dimension!(Pressure, "`{Self}` is not a pressure");
+dimension!(Energy, "`{Self}` is not an energy");
+dimension!(Power, "`{Self}` is not a power");
+dimension!(Momentum, "`{Self}` is not a momentum");
+dimension!(Torque, "`{Self}` is not a torque");
+dimension!(SurfaceTension, "`{Self}` is not a surface tension");
 
-pub trait Charge: Dimensioned {}
-pub trait Current: Dimensioned {}
-pub trait ElectricPotential: Dimensioned {}
-pub trait Capacitance: Dimensioned {}
-pub trait Resistance: Dimensioned {}
-pub trait Conductance: Dimensioned {}
-pub trait MagneticFlux: Dimensioned {}
-pub trait Inductance: Dimensioned {}
+dimension!(Density, "`{Self}` is not a density");
+dimension!(DynamicViscosity, "`{Self}` is not a dynamic viscosity");
+dimension!(KinematicViscosity, "`{Self}` is not a kinematic viscosity");
+dimension!(MassFlowRate, "`{Self}` is not a mass flow rate");
+dimension!(VolumetricFlowRate, "`{Self}` is not a volumetric flow rate");
 
-pub trait Frequency: Dimensioned {}
+dimension!(HeatCapacity, "`{Self}` is not a heat capacity");
+dimension!(Entropy, "`{Self}` is not an entropy");
+dimension!(
+    SpecificHeatCapacity,
+    "`{Self}` is not a specific heat capacity"
+);
+dimension!(
+    ThermalConductivity,
+    "`{Self}` is not a thermal conductivity"
+);
+dimension!(HeatFluxDensity, "`{Self}` is not a heat flux density");
 
-pub trait Force: Dimensioned {}
-pub trait Pressure: Dimensioned {}
-pub trait Energy: Dimensioned {}
-pub trait Power: Dimensioned {}
+dimension!(AbsorbedDose, "`{Self}` is not an absorbed dose");
+dimension!(EquivalentDose, "`{Self}` is not an equivalent dose");
+dimension!(Radioactivity, "`{Self}` is not a radioactivity");
 
-pub trait AmountOfSubstance: Dimensioned {}
+dimension!(AmountOfSubstance, "`{Self}` is not an amount of substance");
+dimension!(CatalyticActivity, "`{Self}` is not a catalytic activity");
+dimension!(Concentration, "`{Self}` is not a concentration");
+dimension!(MolarMass, "`{Self}` is not a molar mass");
@@ -0,0 +1,48 @@
+//! Quantities whose units are only known at runtime
+//!
+//! Every unit system created with `make_units!` has a type `Dynamic<V>`, holding a value and the
+//! power of each base unit, in the order in which the base units are defined. Its units are
+//! checked when it is used rather than at compile time, so it can hold quantities whose units come
+//! from user input or files.
+//!
+//! Multiplying and dividing `Dynamic` quantities always succeeds. Adding and subtracting them
+//! requires their units to match; the `checked_add()` and `checked_sub()` methods return a
+//! `DimensionMismatch` if they don't, while the `+` and `-` operators panic. Likewise, `powi()`
+//! panics if the power of a base unit overflows, and `checked_powi()` returns `None`.
+//!
+//! Quantities with static units convert into `Dynamic` ones with `From`, and back with `TryFrom`,
+//! which fails if the units don't match.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::si;
+//! use std::convert::TryFrom;
+//!
+//! fn main() {
+//!     let length = si::Dynamic::from(3.0 * si::M);
+//!     let time = si::Dynamic::new(2.0, [0, 0, 1, 0, 0, 0, 0]);
+//!
+//!     let speed = length / time;
+//!     assert_eq!(si::MeterPerSecond::try_from(speed), Ok(1.5 * si::MPS));
+//!     assert!(si::Second::try_from(speed).is_err());
+//!
+//!     assert!(length.checked_add(time).is_err());
+//! }
+//! ```
+
+use core::fmt;
+
+/// The error when the units of a `Dynamic` quantity don't match the units they need to have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DimensionMismatch;
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "mismatched units")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimensionMismatch {}
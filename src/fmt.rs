@@ -1,39 +1,639 @@
+//! Formatting quantities
+//!
+//! Unit systems created with `make_units!` (with `fmt = true`) implement all of the formatting
+//! traits from `core::fmt` except `Debug` by formatting the value, followed by a single space and
+//! the units of the quantity if it has any. When the units match a derived unit that has been
+//! given a print symbol, that symbol is written; otherwise, they are written in terms of base
+//! units. `Debug` always uses base units.
+//!
+//! The units are written through the [`FmtUnits`](trait.FmtUnits.html) trait, and the adaptors in
+//! this module use it to format quantities differently, while still respecting the format spec
+//! for the value: [`BaseUnits`](struct.BaseUnits.html) writes units in terms of base units,
+//! [`Engineering`](struct.Engineering.html) picks an SI prefix for the value, and
+//! [`Styled`](struct.Styled.html) writes units as Unicode, LaTeX or for siunitx.
+//!
+//! To display a quantity in some other unit, such as kilometers, use the `display_in()` method of
+//! its unit system, which returns a [`DisplayIn`](struct.DisplayIn.html).
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::fmt::BaseUnits;
+//! use dim::si;
+//!
+//! fn main() {
+//!     let f = 3.5 * si::N;
+//!     assert_eq!(format!("{}", f), "3.5 N");
+//!     assert_eq!(format!("{:.2}", BaseUnits(&f)), "3.50 m*kg*s^-2");
+//!     assert_eq!(format!("{:?}", f), "3.5 m*kg*s^-2");
+//! }
+//! ```
+
+use crate::parse::{self, ParseQuantityError};
+use crate::Dimensioned;
 use core::fmt;
+use core::str::FromStr;
+use num_traits::float::FloatCore;
+use num_traits::{FromPrimitive, One, Zero};
 use typenum::Integer;
 
+/// The styles in which units can be written
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Plain text, as used by the formatting traits; e.g. `m*kg*s^-2`.
+    Ascii,
+    /// Unicode text with superscript exponents; e.g. `m·kg·s⁻²`.
+    Unicode,
+    /// LaTeX math; e.g. `\mathrm{m\,kg\,s^{-2}}`.
+    Latex,
+    /// Units for the LaTeX package siunitx; e.g. `\metre\kilo\gram\per\second\squared`. Symbols
+    /// that siunitx has no unit macro for are written as they are.
+    Siunitx,
+}
+
+/// Writing the units of a quantity type
+///
+/// This is implemented by `make_units!` for unit systems created with `fmt = true`, and by the
+/// `CGS`, `MKS` and `FPS` systems. A unit system with custom formatting may implement it too, to
+/// get its `to_string()` function and the adaptors in this module.
+pub trait FmtUnits {
+    /// Whether this type has no units at all.
+    fn is_unitless() -> bool;
+
+    /// The single symbol that the units of this type are written as, if there is one; e.g. for a
+    /// base unit, or a derived unit with a print symbol.
+    fn unit_symbol() -> Option<&'static str> {
+        None
+    }
+
+    /// Write the units of this type in terms of base units only, in the given style.
+    fn fmt_base_units(f: &mut fmt::Formatter, style: Style) -> fmt::Result;
+
+    /// Write the units of this type in the given style; the formatting traits use
+    /// `Style::Ascii`. By default, this writes `unit_symbol()` if there is one, and base units
+    /// otherwise.
+    fn fmt_units(f: &mut fmt::Formatter, style: Style) -> fmt::Result {
+        match Self::unit_symbol() {
+            Some(symbol) => write_units(f, style, Some((symbol, 1)), 1),
+            None => Self::fmt_base_units(f, style),
+        }
+    }
+}
+
+/// Allocation-free access to the units of a quantity type
+///
+/// This is implemented by `make_units!` for every unit system whose quantities implement
+/// [`FmtUnits`](trait.FmtUnits.html), including all of the unit systems in dimensioned, and is
+/// available without `std`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::fmt::WriteUnits;
+/// use dim::si;
+///
+/// fn main() {
+///     let mut units = String::new();
+///     si::MeterPerSecond2::<f64>::write_units(&mut units).unwrap();
+///     assert_eq!(units, "m*s^-2");
+///
+///     assert_eq!(si::Newton::<f64>::EXPONENTS, &[1, 1, -2, 0, 0, 0, 0]);
+/// }
+/// ```
+pub trait WriteUnits {
+    /// The power of each base unit of this type, in the order in which the base units are defined.
+    const EXPONENTS: &'static [isize];
+
+    /// Write the units of this type to `w`, as the formatting traits do.
+    fn write_units<W: fmt::Write>(w: &mut W) -> fmt::Result;
+}
+
+/// Write units, given as symbols with the powers they are raised to, in `style`. Exponents are in
+/// multiples of `1/denominator`, and symbols with an exponent of 0 are skipped.
+#[doc(hidden)]
+pub fn write_units<'a, I>(
+    f: &mut fmt::Formatter,
+    style: Style,
+    units: I,
+    denominator: isize,
+) -> fmt::Result
+where
+    I: IntoIterator<Item = (&'a str, isize)>,
+{
+    if style == Style::Latex {
+        write!(f, "\\mathrm{{")?;
+    }
+
+    let mut first = true;
+    for (symbol, exp) in units.into_iter().filter(|&(_, exp)| exp != 0) {
+        if !first {
+            match style {
+                Style::Ascii => write!(f, "*")?,
+                Style::Unicode => write!(f, "·")?,
+                Style::Latex => write!(f, "\\,")?,
+                Style::Siunitx => (),
+            }
+        }
+        first = false;
+
+        if style == Style::Siunitx && exp < 0 {
+            write!(f, "\\per")?;
+        }
+        write_symbol(f, style, symbol)?;
+        write_exponent(f, style, exp, denominator)?;
+    }
+
+    if style == Style::Latex {
+        write!(f, "}}")?;
+    }
+    Ok(())
+}
+
+fn write_symbol(f: &mut fmt::Formatter, style: Style, symbol: &str) -> fmt::Result {
+    match style {
+        Style::Ascii | Style::Unicode => write!(f, "{}", symbol),
+        Style::Latex => {
+            for c in symbol.chars() {
+                match c {
+                    'Ω' => write!(f, "\\Omega{{}}")?,
+                    'µ' => write!(f, "\\mu{{}}")?,
+                    _ => write!(f, "{}", c)?,
+                }
+            }
+            Ok(())
+        }
+        Style::Siunitx => {
+            let (exp, unit) = split_prefix(symbol);
+            let unit = SIUNITX_UNITS.iter().find(|&&(s, _)| s == unit);
+            let prefix = SIUNITX_PREFIXES.iter().find(|&&(e, _)| e == exp);
+            match (unit, prefix) {
+                (Some(&(_, unit)), Some(&(_, prefix))) => write!(f, "{}{}", prefix, unit),
+                (Some(&(_, unit)), None) if exp == 0 => write!(f, "{}", unit),
+                _ => write!(f, "{}", symbol),
+            }
+        }
+    }
+}
+
+fn write_exponent(
+    f: &mut fmt::Formatter,
+    style: Style,
+    exp: isize,
+    denominator: isize,
+) -> fmt::Result {
+    let integer = exp % denominator == 0;
+    match style {
+        Style::Ascii if exp == denominator => Ok(()),
+        Style::Ascii if integer => write!(f, "^{}", exp / denominator),
+        Style::Ascii => write!(f, "^{}", exp as f32 / denominator as f32),
+        Style::Unicode if exp == denominator => Ok(()),
+        Style::Unicode => {
+            write_superscript(f, exp / if integer { denominator } else { 1 })?;
+            if !integer {
+                write!(f, "ᐟ")?;
+                write_superscript(f, denominator)?;
+            }
+            Ok(())
+        }
+        Style::Latex if exp == denominator => Ok(()),
+        Style::Latex if integer => write!(f, "^{{{}}}", exp / denominator),
+        Style::Latex => write!(f, "^{{{}/{}}}", exp, denominator),
+        Style::Siunitx => match (exp.abs(), integer) {
+            (e, _) if e == denominator => Ok(()),
+            (e, true) if e == 2 * denominator => write!(f, "\\squared"),
+            (e, true) if e == 3 * denominator => write!(f, "\\cubed"),
+            (e, true) => write!(f, "\\tothe{{{}}}", e / denominator),
+            (e, false) => write!(f, "\\tothe{{{}}}", e as f32 / denominator as f32),
+        },
+    }
+}
+
+fn write_superscript(f: &mut fmt::Formatter, n: isize) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    if n < 0 {
+        write!(f, "⁻")?;
+    }
+    // enough digits for any isize
+    let mut digits = [0; 20];
+    let mut len = 0;
+    let mut n = n.unsigned_abs();
+    loop {
+        digits[len] = n % 10;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    for &d in digits[..len].iter().rev() {
+        write!(f, "{}", DIGITS[d])?;
+    }
+    Ok(())
+}
+
+/// The siunitx macros for unit symbols
+const SIUNITX_UNITS: [(&str, &str); 28] = [
+    ("m", "\\metre"),
+    ("g", "\\gram"),
+    ("s", "\\second"),
+    ("A", "\\ampere"),
+    ("K", "\\kelvin"),
+    ("mol", "\\mole"),
+    ("cd", "\\candela"),
+    ("rad", "\\radian"),
+    ("sr", "\\steradian"),
+    ("Hz", "\\hertz"),
+    ("N", "\\newton"),
+    ("Pa", "\\pascal"),
+    ("J", "\\joule"),
+    ("W", "\\watt"),
+    ("C", "\\coulomb"),
+    ("V", "\\volt"),
+    ("F", "\\farad"),
+    ("Ω", "\\ohm"),
+    ("S", "\\siemens"),
+    ("Wb", "\\weber"),
+    ("T", "\\tesla"),
+    ("H", "\\henry"),
+    ("lm", "\\lumen"),
+    ("lx", "\\lux"),
+    ("Bq", "\\becquerel"),
+    ("Gy", "\\gray"),
+    ("Sv", "\\sievert"),
+    ("kat", "\\katal"),
+];
+
+/// The siunitx macros for SI prefixes, by the powers of ten they stand for
+const SIUNITX_PREFIXES: [(i32, &str); 20] = [
+    (24, "\\yotta"),
+    (21, "\\zetta"),
+    (18, "\\exa"),
+    (15, "\\peta"),
+    (12, "\\tera"),
+    (9, "\\giga"),
+    (6, "\\mega"),
+    (3, "\\kilo"),
+    (2, "\\hecto"),
+    (1, "\\deca"),
+    (-1, "\\deci"),
+    (-2, "\\centi"),
+    (-3, "\\milli"),
+    (-6, "\\micro"),
+    (-9, "\\nano"),
+    (-12, "\\pico"),
+    (-15, "\\femto"),
+    (-18, "\\atto"),
+    (-21, "\\zepto"),
+    (-24, "\\yocto"),
+];
+
+/// Formats a quantity with its units in terms of base units, rather than with the symbol of a
+/// derived unit.
+///
+/// ```rust
+/// # extern crate dimensioned as dim;
+/// use dim::fmt::BaseUnits;
+/// use dim::si;
+/// # fn main() {
+/// assert_eq!(format!("{}", 3.0 * si::J), "3 J");
+/// assert_eq!(format!("{}", BaseUnits(&(3.0 * si::J))), "3 m^2*kg*s^-2");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct BaseUnits<'a, Q: 'a>(pub &'a Q);
+
+macro_rules! format_base_units {
+    ($($Trait:ident)*) => (
+        $(impl<'a, Q> fmt::$Trait for BaseUnits<'a, Q> where
+            Q: Dimensioned + FmtUnits,
+            Q::Value: fmt::$Trait,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.value_unsafe().fmt(f)?;
+                if !Q::is_unitless() {
+                    write!(f, " ")?;
+                    Q::fmt_base_units(f, Style::Ascii)?;
+                }
+                Ok(())
+            }
+        })*
+    );
+}
+
+format_base_units!(Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+
+/// Formats a quantity with its units in a given [`Style`](enum.Style.html).
+///
+/// With `Style::Latex`, the value and units are separated by a thin space, `\,`, and with
+/// `Style::Siunitx`, the whole quantity is written as `\SI{value}{units}`, or `\num{value}`
+/// if it has no units.
+///
+/// ```rust
+/// # extern crate dimensioned as dim;
+/// use dim::fmt::{Style, Styled};
+/// use dim::si;
+/// # fn main() {
+/// let a = 3.5 * si::MPS2;
+/// assert_eq!(format!("{}", Styled::new(&a, Style::Unicode)), "3.5 m·s⁻²");
+/// assert_eq!(
+///     format!("{:.2}", Styled::new(&a, Style::Latex)),
+///     "3.50\\,\\mathrm{m\\,s^{-2}}"
+/// );
+///
+/// let f = 3.5 * si::N;
+/// assert_eq!(
+///     format!("{}", Styled::new(&f, Style::Siunitx)),
+///     "\\SI{3.5}{\\newton}"
+/// );
+/// assert_eq!(
+///     format!("{}", Styled::new(&f, Style::Siunitx).base_units()),
+///     "\\SI{3.5}{\\metre\\kilo\\gram\\per\\second\\squared}"
+/// );
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Styled<'a, Q: 'a> {
+    quantity: &'a Q,
+    style: Style,
+    base_units: bool,
+}
+
+impl<'a, Q> Styled<'a, Q> {
+    /// Format `quantity` with its units in `style`.
+    pub fn new(quantity: &'a Q, style: Style) -> Self {
+        Styled {
+            quantity,
+            style,
+            base_units: false,
+        }
+    }
+
+    /// Write the units in terms of base units, as [`BaseUnits`](struct.BaseUnits.html) does.
+    pub fn base_units(self) -> Self {
+        Styled {
+            base_units: true,
+            ..self
+        }
+    }
+}
+
+macro_rules! format_styled {
+    ($($Trait:ident)*) => (
+        $(impl<'a, Q> fmt::$Trait for Styled<'a, Q> where
+            Q: Dimensioned + FmtUnits,
+            Q::Value: fmt::$Trait,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let value = self.quantity.value_unsafe();
+                match (self.style, Q::is_unitless()) {
+                    (Style::Siunitx, true) => {
+                        write!(f, "\\num{{")?;
+                        value.fmt(f)?;
+                        return write!(f, "}}");
+                    }
+                    (Style::Siunitx, false) => {
+                        write!(f, "\\SI{{")?;
+                        value.fmt(f)?;
+                        write!(f, "}}{{")?;
+                    }
+                    (_, unitless) => {
+                        value.fmt(f)?;
+                        if unitless {
+                            return Ok(());
+                        }
+                        match self.style {
+                            Style::Latex => write!(f, "\\,")?,
+                            _ => write!(f, " ")?,
+                        }
+                    }
+                }
+
+                if self.base_units {
+                    Q::fmt_base_units(f, self.style)?;
+                } else {
+                    Q::fmt_units(f, self.style)?;
+                }
+
+                if self.style == Style::Siunitx {
+                    write!(f, "}}")?;
+                }
+                Ok(())
+            }
+        })*
+    );
+}
+
+format_styled!(Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+
+/// Formats a value followed by a label, for a quantity that is displayed in some unit with the
+/// `display_in()` method of its unit system.
+///
+/// As the unit must be a quantity of the same type, displaying in a unit of the wrong dimension is
+/// a compile-time error.
+///
+/// ```rust
+/// # extern crate dimensioned as dim;
+/// use dim::f64prefixes::KILO;
+/// use dim::si;
+/// # fn main() {
+/// let distance = 42_195.0 * si::M;
+/// assert_eq!(
+///     format!("{:.1}", distance.display_in(KILO * si::M, "km")),
+///     "42.2 km"
+/// );
+/// assert_eq!(
+///     format!("{:.2}", distance.display_in(si::MI, "mi")),
+///     "26.22 mi"
+/// );
+///
+/// let speed = 25.0 * si::MPS;
+/// assert_eq!(
+///     format!("{}", speed.display_in(KILO * si::M / si::HR, "km/h")),
+///     "90 km/h"
+/// );
+/// # }
+/// ```
+///
+/// ```rust,compile_fail
+/// # extern crate dimensioned as dim;
+/// use dim::si;
+/// # fn main() {
+/// let distance = 42_195.0 * si::M;
+/// println!("{}", distance.display_in(si::HR, "h"));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayIn<'a, V> {
+    value: V,
+    label: &'a str,
+}
+
+impl<'a, V> DisplayIn<'a, V> {
+    /// Format `value` followed by `label`; nothing follows the value if `label` is empty.
+    pub fn new(value: V, label: &'a str) -> Self {
+        DisplayIn { value, label }
+    }
+}
+
+macro_rules! format_display_in {
+    ($($Trait:ident)*) => (
+        $(impl<'a, V: fmt::$Trait> fmt::$Trait for DisplayIn<'a, V> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.value.fmt(f)?;
+                if !self.label.is_empty() {
+                    write!(f, " {}", self.label)?;
+                }
+                Ok(())
+            }
+        })*
+    );
+}
+
+format_display_in!(Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+
+/// The SI prefixes, with the powers of ten they stand for.
+const PREFIXES: [(&str, i32); 21] = [
+    ("da", 1),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+];
+
+/// Split a unit symbol into the power of ten of its SI prefix, if it has one, and the rest.
+///
+/// Since e.g. `"Pa"` and `"cd"` also start with prefixes, a symbol only counts as prefixed if the
+/// rest of it starts with a capital letter, or is one of the unit symbols that don't.
+fn split_prefix(symbol: &str) -> (i32, &str) {
+    const LOWERCASE: [&str; 10] = ["g", "m", "s", "mol", "cd", "lm", "lx", "rad", "sr", "kat"];
+
+    PREFIXES
+        .iter()
+        .filter_map(|&(prefix, exp)| {
+            let rest = symbol.strip_prefix(prefix)?;
+            let capital = rest.chars().next()?;
+            if capital.is_uppercase() || !capital.is_ascii() || LOWERCASE.contains(&rest) {
+                Some((exp, rest))
+            } else {
+                None
+            }
+        })
+        .next()
+        .unwrap_or((0, symbol))
+}
+
+/// Formats a quantity in engineering notation, picking the SI prefix that puts its value between
+/// 1 and 1000.
+///
+/// This only applies to quantities whose units are written as a single symbol, such as `m` or
+/// `nF`; others are formatted as they would be otherwise. Values too big or small for any prefix
+/// get the largest or smallest one.
+///
+/// ```rust
+/// # extern crate dimensioned as dim;
+/// use dim::fmt::Engineering;
+/// use dim::si;
+/// # fn main() {
+/// assert_eq!(format!("{}", Engineering(&(0.0032 * si::M))), "3.2 mm");
+/// assert_eq!(format!("{}", Engineering(&(4.7e-9 * si::F))), "4.7 nF");
+/// assert_eq!(format!("{:.2}", Engineering(&(1500.0 * si::KG))), "1.50 Mg");
+/// assert_eq!(format!("{}", Engineering(&(3.0 * si::MPS))), "3 m*s^-1");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Engineering<'a, Q: 'a>(pub &'a Q);
+
+impl<'a, Q> fmt::Display for Engineering<'a, Q>
+where
+    Q: Dimensioned + FmtUnits,
+    Q::Value: FloatCore + FromPrimitive + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = *self.0.value_unsafe();
+        let ten = Q::Value::from_f64(10.0);
+        let (symbol, ten) = match (Q::unit_symbol(), ten) {
+            (Some(symbol), Some(ten)) if value.is_finite() && !value.is_zero() => (symbol, ten),
+            _ => {
+                value.fmt(f)?;
+                if !Q::is_unitless() {
+                    write!(f, " ")?;
+                    Q::fmt_units(f, Style::Ascii)?;
+                }
+                return Ok(());
+            }
+        };
+
+        // The value is `value * 10^shift` in the unprefixed unit; we want the largest power of
+        // 1000 that leaves it at least 1. Only ever scaling by whole powers of ten keeps exact
+        // powers of 1000 exact, as `10^-3` is not.
+        let (shift, unit) = split_prefix(symbol);
+        let scale = |exp: i32| {
+            if shift >= exp {
+                value * ten.powi(shift - exp)
+            } else {
+                value / ten.powi(exp - shift)
+            }
+        };
+        let exp = (-8..=8)
+            .rev()
+            .map(|e| e * 3)
+            .find(|&e| scale(e).abs() >= Q::Value::one())
+            .unwrap_or(-24);
+        let value = scale(exp);
+        let prefix = PREFIXES
+            .iter()
+            .find(|&&(_, e)| e == exp)
+            .map_or("", |&(prefix, _)| prefix);
+
+        value.fmt(f)?;
+        write!(f, " {}{}", prefix, unit)
+    }
+}
+
 macro_rules! format_cgs_like {
     ($System:ident; $tokens:expr; $($Trait:ident)*) => (
+        impl<V, U1, U2, U3> FmtUnits for $System<V, tarr![U1, U2, U3]> where
+            U1: Integer, U2: Integer, U3: Integer,
+        {
+            fn is_unitless() -> bool {
+                U1::to_isize() == 0 && U2::to_isize() == 0 && U3::to_isize() == 0
+            }
+
+            fn fmt_base_units(f: &mut fmt::Formatter, style: Style) -> fmt::Result {
+                // double U3 so we can treat them all the same, as sqrts
+                let exponents = [U1::to_isize(), U2::to_isize(), U3::to_isize()*2];
+                write_units(f, style, $tokens.iter().cloned().zip(exponents.iter().cloned()), 2)
+            }
+        }
+
         $(impl<V, U1, U2, U3> fmt::$Trait for $System<V, tarr![U1, U2, U3]> where
             V: fmt::$Trait, U1: Integer, U2: Integer, U3: Integer,
         {
             fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
             {
-                // double U3 so we can treat them all the same, as sqrts
-                let exponents = [U1::to_isize(), U2::to_isize(), U3::to_isize()*2];
-                let print_tokens = $tokens;
-
                 self.value_unsafe.fmt(f)?;
-
-                let mut first = true;
-                for (&exp, token) in
-                    exponents.iter()
-                    .zip(print_tokens.iter())
-                {
-                    if first {
-                        if exp != 0 {
-                            first = false;
-                            write!(f, " ")?;
-                        }
-                    } else if exp != 0 {
-                        write!(f, "*")?;
-                    }
-
-                    match exp {
-                        0 => (),
-                        2 => write!(f, "{}", token)?,
-                        _ if exp % 2 == 0 => write!(f, "{}^{}", token, exp/2)?,
-                        _ => write!(f, "{}^{}", token, (exp as f32)/2.0)?,
-                    }
+                if !Self::is_unitless() {
+                    write!(f, " ")?;
+                    Self::fmt_units(f, Style::Ascii)?;
                 }
                 Ok(())
             }
@@ -41,11 +641,42 @@ macro_rules! format_cgs_like {
     );
 }
 
+macro_rules! parse_cgs_like {
+    ($System:ident; $tokens:expr) => {
+        impl<V, U1, U2, U3> FromStr for $System<V, tarr![U1, U2, U3]>
+        where
+            V: FromStr,
+            U1: Integer,
+            U2: Integer,
+            U3: Integer,
+        {
+            type Err = ParseQuantityError<V::Err>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (value, units) = parse::split_quantity(s);
+                let value = V::from_str(value).map_err(ParseQuantityError::Value)?;
+
+                // exponents are printed as multiples of 1/2, as in the formatting above
+                let mut exponents = [0; 3];
+                parse::parse_units(units, &$tokens, &mut exponents, 2)?;
+                if exponents != [U1::to_isize(), U2::to_isize(), U3::to_isize() * 2] {
+                    return Err(ParseQuantityError::UnitMismatch);
+                }
+
+                Ok($System::new(value))
+            }
+        }
+    };
+}
+
 use crate::unit_systems::cgs::CGS;
 format_cgs_like!(CGS; ["cm", "g", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+parse_cgs_like!(CGS; ["cm", "g", "s"]);
 
 use crate::unit_systems::mks::MKS;
 format_cgs_like!(MKS; ["m", "k", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+parse_cgs_like!(MKS; ["m", "k", "s"]);
 
 use crate::unit_systems::fps::FPS;
 format_cgs_like!(FPS; ["ft", "lb", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+parse_cgs_like!(FPS; ["ft", "lb", "s"]);
@@ -0,0 +1,227 @@
+//! Dimensions of the International System of Quantities, independent of unit system
+//!
+//! The traits in the `dimensions` module say whether a type is, for example, a length, but they
+//! are implemented for particular units, so they can't say what dimension a computed type such as
+//! `Quot<Prod<L, L>, T>` has. Here, a dimension is a type-level array of the exponents of the
+//! seven base quantities of the ISQ: length, mass, time, electric current, thermodynamic
+//! temperature, amount of substance and luminous intensity, in that order. The trait
+//! `IsqDimension` maps the units of a quantity to its dimension, so generic code can require a
+//! dimension of any type, whichever unit system it comes from.
+//!
+//! `IsqDimension` is implemented for all of the unit systems in this library, as follows:
+//!
+//! * `SI`: The base units are those of the ISQ.
+//!
+//! * `SIA` and `UCUM`: Angles are dimensionless in the ISQ, so the radian is discarded, as when
+//!   converting to `SI`. The `UCUM` coulomb is an ampere second, and as `UCUM` has no unit for
+//!   amount of substance, its exponent is always zero.
+//!
+//! * `CGS`, `MKS` and `FPS`: Their base units are square roots of length and mass, so this is
+//!   only implemented for units with whole powers of length and mass. That rules out the Gaussian
+//!   electromagnetic units, such as `cgs::StatCoulomb`, which have no equivalent in the ISQ.
+//!
+//! The `Dimension` of a quantity can be compared with the type aliases in this module. These
+//! aliases have the same names as the traits in `dimensions`, and are a different thing, so it's
+//! best to refer to them with the module name, as in `isq::Energy`.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::isq::{self, IsqDimension};
+//! use dim::typenum::Prod;
+//! use std::ops::Mul;
+//!
+//! fn work<F, L>(force: F, distance: L) -> Prod<F, L>
+//! where
+//!     F: Mul<L>,
+//!     Prod<F, L>: IsqDimension<Dimension = isq::Energy>,
+//! {
+//!     force * distance
+//! }
+//!
+//! fn main() {
+//!     use dim::{cgs, si};
+//!
+//!     assert_eq!(work(2.0 * si::N, 3.0 * si::M), 6.0 * si::J);
+//!     assert_eq!(work(2.0 * cgs::DYN, 3.0 * cgs::CM), 6.0 * cgs::ERG);
+//!
+//!     assert_eq!(si::Joule::<f64>::DIMENSION, [2, 1, -2, 0, 0, 0, 0]);
+//! }
+//! ```
+//!
+//! ```rust,compile_fail,E0271
+//! # extern crate dimensioned as dim;
+//! # use dim::isq::{self, IsqDimension};
+//! # use dim::typenum::Prod;
+//! # use std::ops::Mul;
+//! # fn work<F, L>(force: F, distance: L) -> Prod<F, L>
+//! # where
+//! #     F: Mul<L>,
+//! #     Prod<F, L>: IsqDimension<Dimension = isq::Energy>,
+//! # {
+//! #     force * distance
+//! # }
+//! # fn main() {
+//! use dim::si;
+//!
+//! // error: a newton second is not an energy
+//! work(2.0 * si::N, 3.0 * si::S);
+//! # }
+//! ```
+
+use crate::array::ToArray;
+use crate::typenum::consts::{P1, P2, Z0};
+use crate::typenum::{Diff, Integer, PartialDiv, PartialQuot, Sum};
+use crate::unit_systems::{cgs::CGS, fps::FPS, mks::MKS, si::SI, sia::SIA, ucum::UCUM};
+use crate::Dimensioned;
+use core::ops::Add;
+
+/// Gives the dimension of a quantity in the International System of Quantities.
+#[cfg_attr(
+    diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has no dimension in the International System of Quantities",
+        note = "`IsqDimension` is implemented for the unit systems in this library, for units with a whole power of each base quantity"
+    )
+)]
+pub trait IsqDimension: Dimensioned {
+    /// The exponents of length, mass, time, electric current, thermodynamic temperature, amount of
+    /// substance and luminous intensity, as a type-level array. E.g. for `si::Newton<f64>` and
+    /// `cgs::Dyne<f64>`, `Dimension` is `tarr![P1, P1, N2, Z0, Z0, Z0, Z0]`.
+    type Dimension: ToArray<7>;
+
+    /// The exponents in `Dimension`, as an array.
+    const DIMENSION: [isize; 7] = <Self::Dimension as ToArray<7>>::ARRAY;
+}
+
+/// Dimensionless, as for a ratio of two lengths.
+pub type Dimensionless = tarr![Z0, Z0, Z0, Z0, Z0, Z0, Z0];
+/// L
+pub type Length = tarr![P1, Z0, Z0, Z0, Z0, Z0, Z0];
+/// M
+pub type Mass = tarr![Z0, P1, Z0, Z0, Z0, Z0, Z0];
+/// T
+pub type Time = tarr![Z0, Z0, P1, Z0, Z0, Z0, Z0];
+/// I
+pub type Current = tarr![Z0, Z0, Z0, P1, Z0, Z0, Z0];
+/// Θ
+pub type Temperature = tarr![Z0, Z0, Z0, Z0, P1, Z0, Z0];
+/// N
+pub type AmountOfSubstance = tarr![Z0, Z0, Z0, Z0, Z0, P1, Z0];
+/// J
+pub type LuminousIntensity = tarr![Z0, Z0, Z0, Z0, Z0, Z0, P1];
+
+/// L²
+pub type Area = Sum<Length, Length>;
+/// L³
+pub type Volume = Sum<Area, Length>;
+/// L⁻¹
+pub type ReciprocalLength = Diff<Dimensionless, Length>;
+/// T⁻¹
+pub type Frequency = Diff<Dimensionless, Time>;
+/// L T⁻¹
+pub type Velocity = Diff<Length, Time>;
+/// L T⁻²
+pub type Acceleration = Diff<Velocity, Time>;
+/// L T⁻³
+pub type Jerk = Diff<Acceleration, Time>;
+/// M L T⁻¹
+pub type Momentum = Sum<Mass, Velocity>;
+/// M L T⁻²
+pub type Force = Sum<Mass, Acceleration>;
+/// M L⁻¹ T⁻²
+pub type Pressure = Diff<Force, Area>;
+/// M L² T⁻²
+pub type Energy = Sum<Force, Length>;
+/// M L² T⁻², the same as energy
+pub type Torque = Energy;
+/// M L² T⁻³
+pub type Power = Diff<Energy, Time>;
+/// M L⁻³
+pub type Density = Diff<Mass, Volume>;
+/// M L⁻¹ T⁻¹
+pub type DynamicViscosity = Sum<Pressure, Time>;
+/// L² T⁻¹
+pub type KinematicViscosity = Diff<Area, Time>;
+/// T I
+pub type Charge = Sum<Time, Current>;
+/// M L² T⁻³ I⁻¹
+pub type ElectricPotential = Diff<Power, Current>;
+/// M⁻¹ L⁻² T⁴ I²
+pub type Capacitance = Diff<Charge, ElectricPotential>;
+/// M L² T⁻³ I⁻²
+pub type Resistance = Diff<ElectricPotential, Current>;
+/// M⁻¹ L⁻² T³ I²
+pub type Conductance = Diff<Dimensionless, Resistance>;
+/// M L² T⁻² I⁻¹
+pub type MagneticFlux = Sum<ElectricPotential, Time>;
+/// M T⁻² I⁻¹
+pub type MagneticFluxDensity = Diff<MagneticFlux, Area>;
+/// M L² T⁻² I⁻²
+pub type Inductance = Diff<MagneticFlux, Current>;
+/// M L² T⁻² Θ⁻¹
+pub type HeatCapacity = Diff<Energy, Temperature>;
+/// M L T⁻³ Θ⁻¹
+pub type ThermalConductivity = Diff<Diff<Power, Length>, Temperature>;
+/// N T⁻¹
+pub type CatalyticActivity = Diff<AmountOfSubstance, Time>;
+/// J L⁻²
+pub type Illuminance = Diff<LuminousIntensity, Area>;
+
+impl<V, L, M, T, I, Th, J, N> IsqDimension for SI<V, tarr![L, M, T, I, Th, J, N]>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    J: Integer,
+    N: Integer,
+{
+    type Dimension = tarr![L, M, T, I, Th, N, J];
+}
+
+impl<V, L, M, T, I, Th, J, N, Rad> IsqDimension for SIA<V, tarr![L, M, T, I, Th, J, N, Rad]>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    J: Integer,
+    N: Integer,
+{
+    type Dimension = tarr![L, M, T, I, Th, N, J];
+}
+
+impl<V, L, T, M, Rad, Th, Q, J> IsqDimension for UCUM<V, tarr![L, T, M, Rad, Th, Q, J]>
+where
+    L: Integer,
+    T: Integer + Add<Q>,
+    M: Integer,
+    Th: Integer,
+    Q: Integer,
+    J: Integer,
+    Sum<T, Q>: Integer,
+{
+    type Dimension = tarr![L, M, Sum<T, Q>, Q, Th, Z0, J];
+}
+
+macro_rules! impl_isq_sqrt {
+    ($($System:ident),*) => (
+        $(impl<V, SqrtL, SqrtM, T> IsqDimension for $System<V, tarr![SqrtL, SqrtM, T]>
+        where
+            SqrtL: PartialDiv<P2>,
+            SqrtM: PartialDiv<P2>,
+            T: Integer,
+            PartialQuot<SqrtL, P2>: Integer,
+            PartialQuot<SqrtM, P2>: Integer,
+        {
+            type Dimension =
+                tarr![PartialQuot<SqrtL, P2>, PartialQuot<SqrtM, P2>, T, Z0, Z0, Z0, Z0];
+        })*
+    );
+}
+
+impl_isq_sqrt!(CGS, MKS, FPS);
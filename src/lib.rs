@@ -75,6 +75,42 @@ If you try to add a `Meter<f64>` to a `Second<f64>`, then you will get a compile
 they have different types and so addition is not defined. Multiplication, on the other hand, is
 defined, and results in a normal multiplication of the value types, and the unit powers added.
 
+```rust,compile_fail,E0308
+# extern crate dimensioned as dim;
+# use dim::si;
+# fn main() {
+// error: mismatched types
+let x = 3.0 * si::M + 2.0 * si::S;
+# }
+```
+
+The same goes for subtracting, and for comparing, quantities with different units:
+
+```rust,compile_fail,E0308
+# extern crate dimensioned as dim;
+# use dim::si;
+# fn main() {
+let x = 3.0 * si::M - 2.0 * si::S;
+# }
+```
+
+```rust,compile_fail,E0308
+# extern crate dimensioned as dim;
+# use dim::si;
+# fn main() {
+let mut x = 3.0 * si::M;
+x += 2.0 * si::S;
+# }
+```
+
+```rust,compile_fail,E0308
+# extern crate dimensioned as dim;
+# use dim::si;
+# fn main() {
+let longer = 3.0 * si::M > 2.0 * si::S;
+# }
+```
+
 So, multiplying `Meter<f64>` by `Second<f64>` gives `SI<f64, tarr![P1, Z0, P1, Z0, Z0, Z0,
 Z0]>`.
 
@@ -92,6 +128,32 @@ fn main() {
 }
 ```
 
+To get a plain number back out of a quantity, use `value_in()` with a unit of the same type. This
+is dimensionally safe, unlike the `value_unsafe` member, as units that don't match won't compile.
+
+```rust
+extern crate dimensioned as dim;
+
+use dim::si;
+
+fn main() {
+    let d = 8046.72 * si::M;
+    assert_eq!(d.value_in(si::MI), 5.0);
+    assert_eq!(d.value_in(si::M), 8046.72);
+}
+```
+
+```rust,compile_fail
+extern crate dimensioned as dim;
+
+use dim::si;
+
+fn main() {
+    let d = 8046.72 * si::M;
+    d.value_in(si::S);
+}
+```
+
 That's basically it. All of the dimensional safety comes from whether things typecheck, and from
 performing type-level arithmetic, thanks to the [typenum](http://paholg.com/typenum/)
 crate. Pretty much everything else is for ergonomics.
@@ -110,7 +172,10 @@ crate. Pretty much everything else is for ergonomics.
 // fixme: See if we can use min_specialization instead.
 #![cfg_attr(feature = "spec", feature(specialization))]
 #![cfg_attr(feature = "spec", allow(incomplete_features))]
-#![cfg_attr(not(feature = "std"), feature(core_intrinsics))]
+#![cfg_attr(
+    all(not(feature = "std"), feature = "nightly"),
+    feature(core_intrinsics)
+)]
 #![allow(
     clippy::float_cmp,
     clippy::useless_attribute,
@@ -133,7 +198,10 @@ extern crate auto_args;
 extern crate clapme;
 #[cfg(feature = "std")]
 extern crate core;
-extern crate num_traits;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub extern crate dimensioned_macros;
+pub extern crate num_traits;
 pub extern crate typenum;
 
 // Macro debugging
@@ -189,21 +257,31 @@ pub extern crate rand;
 
 #[macro_use]
 mod make_units;
-mod fmt;
 
 include!(concat!(env!("OUT_DIR"), "/unit_systems.rs"));
 pub mod array;
 pub mod conversion;
 pub mod dimensions;
+pub mod dynamic;
 pub mod f32prefixes;
 pub mod f64prefixes;
+pub mod fmt;
+pub mod isq;
+#[cfg(any(feature = "std", feature = "nightly"))]
+pub mod logarithmic;
+#[cfg(feature = "macros")]
+pub mod macros;
+pub mod parse;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+pub mod temperature;
 pub mod traits;
 
 pub use crate::traits::*;
-pub use crate::unit_systems::{cgs, fps, mks, si, ucum};
+pub use crate::unit_systems::{cgs, fps, mks, si, sia, ucum};
 
 // Used for the make_units macro
 #[doc(hidden)]
 pub mod dimcore {
-    pub use core::{default, f32, f64, fmt, iter, marker, mem, ops};
+    pub use core::{convert, default, f32, f64, fmt, iter, marker, mem, ops, str};
 }
@@ -0,0 +1,258 @@
+//! Logarithmic units: decibels, bels, nepers and pH
+//!
+//! A level expresses a quantity as the logarithm of its ratio to a reference quantity, such as
+//! a sound pressure in decibels relative to 20 µPa, or a power in decibels relative to 1 mW (dBm).
+//! As this takes more than multiplication, levels are not units of a unit system, but a type,
+//! `Level<Q>`, holding a value in decibels along with a `Reference<Q>`.
+//!
+//! A reference is either a power quantity, such as power or intensity, for which a ratio of 10 is
+//! 10 dB, or a root-power quantity, such as sound pressure or voltage, for which a ratio of 10 is
+//! 20 dB. Common references are defined as constants in this module.
+//!
+//! Adding two levels adds the powers they represent, as for two uncorrelated sources, so two
+//! levels of 90 dB add to about 93 dB. To apply a gain instead, use `amplify()`.
+//!
+//! Levels are only implemented for `f32` and `f64` values, and require the `std` or `nightly`
+//! feature.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::logarithmic::{Level, DBM, SPL};
+//! use dim::si;
+//!
+//! fn main() {
+//!     let loud = Level::from_quantity(2.0 * si::PA, SPL);
+//!     assert_eq!(format!("{:.1}", loud), "100.0 dB re 20 µPa");
+//!
+//!     let total = loud + loud;
+//!     assert_eq!(format!("{:.1}", total), "103.0 dB re 20 µPa");
+//!
+//!     let transmitted = Level::new(30.0, DBM);
+//!     assert!((transmitted.quantity() / si::W - 1.0).abs() < 1e-12);
+//! }
+//! ```
+
+use crate::{si, Dimensioned};
+use core::fmt;
+use core::ops::Add;
+use num_traits::float::FloatCore;
+
+/// Floating point types that levels may be made of.
+pub trait Logarithm: FloatCore {
+    /// The base 10 logarithm of `self`.
+    fn log10(self) -> Self;
+
+    /// Ten raised to the power `self`.
+    fn exp10(self) -> Self;
+}
+
+macro_rules! impl_logarithm {
+    ($t:ty, $log10:ident, $pow:ident) => {
+        impl Logarithm for $t {
+            fn log10(self) -> Self {
+                #[cfg(feature = "std")]
+                return self.log10();
+                #[cfg(not(feature = "std"))]
+                return core::intrinsics::$log10(self);
+            }
+
+            fn exp10(self) -> Self {
+                #[cfg(feature = "std")]
+                return <$t>::powf(10.0, self);
+                #[cfg(not(feature = "std"))]
+                return core::intrinsics::$pow(10.0, self);
+            }
+        }
+    };
+}
+
+impl_logarithm!(f32, log10f32, powf32);
+impl_logarithm!(f64, log10f64, powf64);
+
+/// Convert an `f64` constant to the value type of a level.
+fn lit<V: FloatCore>(x: f64) -> V {
+    V::from(x).expect("level constants are representable by floating point types")
+}
+
+/// Whether a reference quantity is a power or a root-power quantity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A quantity proportional to power, such as power, energy or intensity.
+    Power,
+    /// A quantity whose square is proportional to power, such as pressure, voltage or current.
+    RootPower,
+}
+
+impl Kind {
+    /// The number of decibels in a ratio of 10 for this kind of quantity.
+    fn decibels_per_decade<V: FloatCore>(self) -> V {
+        match self {
+            Kind::Power => lit(10.0),
+            Kind::RootPower => lit(20.0),
+        }
+    }
+}
+
+/// The quantity that a level is relative to, with the name it is printed with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reference<Q> {
+    /// The reference quantity, which has a level of 0 dB.
+    pub quantity: Q,
+    /// Whether `quantity` is a power or root-power quantity.
+    pub kind: Kind,
+    /// How the reference is printed, as in `"20 µPa"`.
+    pub name: &'static str,
+}
+
+impl<Q> Reference<Q> {
+    /// Create a reference for a power quantity.
+    pub const fn power(quantity: Q, name: &'static str) -> Self {
+        Reference {
+            quantity,
+            kind: Kind::Power,
+            name,
+        }
+    }
+
+    /// Create a reference for a root-power quantity.
+    pub const fn root_power(quantity: Q, name: &'static str) -> Self {
+        Reference {
+            quantity,
+            kind: Kind::RootPower,
+            name,
+        }
+    }
+}
+
+/// Sound pressure level, relative to 20 µPa.
+pub const SPL: Reference<si::Pascal<f64>> = Reference::root_power(si::Pascal::new(20e-6), "20 µPa");
+/// Sound intensity level, relative to 1 pW/m².
+pub const SIL: Reference<si::WattPerMeter2<f64>> =
+    Reference::power(si::WattPerMeter2::new(1e-12), "1 pW/m²");
+/// Sound power level, relative to 1 pW.
+pub const SWL: Reference<si::Watt<f64>> = Reference::power(si::Watt::new(1e-12), "1 pW");
+/// Power relative to 1 mW, or dBm.
+pub const DBM: Reference<si::Watt<f64>> = Reference::power(si::Watt::new(1e-3), "1 mW");
+/// Power relative to 1 W, or dBW.
+pub const DBW: Reference<si::Watt<f64>> = Reference::power(si::Watt::new(1.0), "1 W");
+/// Voltage relative to 1 V, or dBV.
+pub const DBV: Reference<si::Volt<f64>> = Reference::root_power(si::Volt::new(1.0), "1 V");
+/// Voltage relative to the voltage that dissipates 1 mW in 600 Ω, or dBu.
+pub const DBU: Reference<si::Volt<f64>> =
+    Reference::root_power(si::Volt::new(0.774_596_669_241_483_4), "0.775 V");
+
+/// A quantity, expressed as a level in decibels relative to a reference.
+///
+/// See the [module documentation](index.html) for more information.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Level<Q: Dimensioned> {
+    decibels: Q::Value,
+    reference: Reference<Q>,
+}
+
+impl<Q, V> Level<Q>
+where
+    Q: Dimensioned<Value = V> + Copy,
+    V: Logarithm,
+{
+    /// Create a level of `decibels` relative to `reference`.
+    pub fn new(decibels: V, reference: Reference<Q>) -> Self {
+        Level {
+            decibels,
+            reference,
+        }
+    }
+
+    /// Create a level of `bels` relative to `reference`.
+    pub fn from_bels(bels: V, reference: Reference<Q>) -> Self {
+        Level::new(bels * lit(10.0), reference)
+    }
+
+    /// Create a level of `nepers` relative to `reference`.
+    pub fn from_nepers(nepers: V, reference: Reference<Q>) -> Self {
+        Level::new(nepers * lit(20.0 / core::f64::consts::LN_10), reference)
+    }
+
+    /// Express the linear quantity `quantity` as a level relative to `reference`.
+    pub fn from_quantity(quantity: Q, reference: Reference<Q>) -> Self {
+        let ratio = *quantity.value_unsafe() / *reference.quantity.value_unsafe();
+        Level::new(
+            reference.kind.decibels_per_decade::<V>() * ratio.log10(),
+            reference,
+        )
+    }
+
+    /// The level in decibels.
+    pub fn decibels(&self) -> V {
+        self.decibels
+    }
+
+    /// The level in bels.
+    pub fn bels(&self) -> V {
+        self.decibels / lit(10.0)
+    }
+
+    /// The level in nepers.
+    pub fn nepers(&self) -> V {
+        self.decibels * lit(core::f64::consts::LN_10 / 20.0)
+    }
+
+    /// The reference of this level.
+    pub fn reference(&self) -> Reference<Q> {
+        self.reference
+    }
+
+    /// The linear quantity that this level represents.
+    pub fn quantity(&self) -> Q {
+        let exponent = self.decibels / self.reference.kind.decibels_per_decade::<V>();
+        Q::new(*self.reference.quantity.value_unsafe() * exponent.exp10())
+    }
+
+    /// Express this level relative to a different reference.
+    pub fn relative_to(self, reference: Reference<Q>) -> Self {
+        Level::from_quantity(self.quantity(), reference)
+    }
+
+    /// Apply a gain of `decibels`, or an attenuation if it is negative.
+    pub fn amplify(self, decibels: V) -> Self {
+        Level::new(self.decibels + decibels, self.reference)
+    }
+}
+
+/// Add the powers that two levels represent. The result is relative to the reference of `self`.
+impl<Q, V> Add for Level<Q>
+where
+    Q: Dimensioned<Value = V> + Copy,
+    V: Logarithm,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let rhs = rhs.relative_to(self.reference);
+        let ten: V = lit(10.0);
+        let power = (self.decibels / ten).exp10() + (rhs.decibels / ten).exp10();
+        Level::new(ten * power.log10(), self.reference)
+    }
+}
+
+impl<Q> fmt::Display for Level<Q>
+where
+    Q: Dimensioned,
+    Q::Value: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.decibels, f)?;
+        write!(f, " dB re {}", self.reference.name)
+    }
+}
+
+/// The pH of a solution with hydrogen ion activity `concentration`, relative to 1 mol/L.
+pub fn ph<V: Logarithm>(concentration: si::MolePerMeter3<V>) -> V {
+    -(concentration.value_unsafe / lit(1000.0)).log10()
+}
+
+/// The hydrogen ion activity of a solution with pH `ph`, relative to 1 mol/L.
+pub fn from_ph<V: Logarithm>(ph: V) -> si::MolePerMeter3<V> {
+    si::MolePerMeter3::new(lit::<V>(1000.0) * (-ph).exp10())
+}
@@ -0,0 +1,93 @@
+//! Procedural macros, enabled with the `macros` feature
+//!
+//! The `make_units!` in this module takes the same input as the [`make_units!`](../macro.make_units.html)
+//! at the crate root, and creates the same unit system, but checks the definition first, so that a
+//! mistake is reported where it is made rather than as a failure to match a macro rule, or as an
+//! error deep inside the macro's output. It reports:
+//!
+//! * Derived units made of units that don't exist, or whose definitions refer back to themselves.
+//! * Names that are defined more than once.
+//! * Constants of units that don't exist.
+//! * The same dimension given to two units that are the same type.
+//! * Flags on constants other than `integer`, and powers that aren't integers.
+//!
+//! Derived units are passed on to the declarative macro in terms of base units, so their formulas
+//! are checked here rather than by matching macro rules.
+//!
+//! As with the declarative macro, the macros in this crate must be in scope, so use
+//! `#[macro_use] extern crate dimensioned`.
+//!
+//! # Example
+//! ```rust
+//! # #![cfg_attr(feature = "oibit", feature(auto_traits))]
+//! # #![cfg_attr(feature = "oibit", feature(negative_impls))]
+//! #[macro_use]
+//! extern crate dimensioned as dim;
+//!
+//! mod ms {
+//!     dim::macros::make_units! {
+//!         MS;
+//!         ONE: Unitless;
+//!
+//!         base {
+//!             M: Meter, "m", Length;
+//!             S: Second, "s", Time;
+//!         }
+//!
+//!         derived {
+//!             MPS2: MeterPerSecond2 = (MeterPerSecond / Second), Acceleration;
+//!             MPS: MeterPerSecond = (Meter / Second), Velocity;
+//!         }
+//!
+//!         constants {
+//!             MIN: Second = 60.0, integer;
+//!         }
+//!
+//!         fmt = true;
+//!     }
+//!     pub use self::f64consts::*;
+//! }
+//!
+//! fn main() {
+//!     let a = 3.0 * ms::M / ms::S / ms::S;
+//!     assert_eq!(a, 3.0 * ms::MPS2);
+//!     assert_eq!(format!("{}", ms::MIN), "60 s");
+//!     assert_eq!(ms::u32consts::MIN, ms::Second::new(60));
+//! }
+//! ```
+//!
+//! A typo in a derived unit is pointed out, and only there, not in the units that depend on it:
+//!
+//! ```rust,compile_fail
+//! # #[macro_use]
+//! # extern crate dimensioned as dim;
+//! # mod ms {
+//! dim::macros::make_units! {
+//!     MS;
+//!     ONE: Unitless;
+//!     base {
+//!         M: Meter, "m", Length;
+//!         S: Second, "s", Time;
+//!     }
+//!     derived {
+//!         MPS2: MeterPerSecond2 = (MeterPerSecond / Second), Acceleration;
+//!         MPS: MeterPerSecond = (Metre / Second), Velocity;
+//!         //                     ^^^^^ unknown unit `Metre`
+//!     }
+//!     constants {}
+//!     fmt = true;
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+
+pub use crate::__make_units_proc as make_units;
+
+/// Pass the path to this crate to the procedural macro, which has no other way of knowing it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __make_units_proc {
+    ($($tokens:tt)*) => (
+        $crate::dimensioned_macros::make_units!($crate; $($tokens)*);
+    );
+}
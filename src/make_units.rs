@@ -27,18 +27,21 @@ pub mod ms {
 
         derived {
             MPS: MeterPerSecond = (Meter / Second), Velocity;
-            HZ: Hertz = (Unitless / Second), Frequency;
+            HZ: Hertz, "Hz" = (Unitless / Second), Frequency;
 
-            M3: Meter3 = (Meter * Meter * Meter), Volume;
-            M5: Meter5 = (Meter3 * Meter * Meter);
+            M5: Meter5 = (Meter3 * Meter^2);
+            M3: Meter3 = (Meter^3), Volume;
+            PM5: PerMeter5 = ((Meter / Unitless)^-5);
+
+            KM: Kilometer, "km" = (Meter) * 1000.0;
         }
 
         constants {
             FT: Meter = 0.3048;
             CM: Meter = CENTI * M.value_unsafe;
 
-            MIN: Second = 60.0;
-            HR: Second = 60.0 * MIN.value_unsafe;
+            MIN: Second = 60.0, integer;
+            HR: Second = 60.0 * MIN.value_unsafe, integer;
 
             PI: Unitless = consts::PI;
         }
@@ -88,46 +91,80 @@ optional. If present, the macro will implement said dimension from the `dimensio
 ```
 
 In the `derived` block, we can make derived units from our base units. The beginning is similar; we
-have `CONST: Type`. After the equal signs, we have a formula to define this unit. The parentheses
-are required, and the only things that can be inside them are the names of other units, and the *
-and / operators. Hopefully, this part of the macro will be made more flexibile in the
-future. Finally, we again end with an optional dimension. Note that there is none present for the
-`M5` line.
+have `CONST: Type`, optionally followed by a `"symbol"` that will show up when we print a quantity
+with these units, instead of the base units that make them up. After the equal signs, we have a
+formula to define this unit. The outer parentheses are required. Inside them are the names of other
+units combined with the `*` and `/` operators, which may be raised to integer powers with `^`, as in
+`Meter^3` or `Second^-2`, and grouped with more parentheses, as in `(Meter / Second)^2`. A unit may
+be defined in terms of units that come later in the block, as long as no definition refers back to
+itself.
+
+A derived unit has a value of 1 unless it is followed by a scale, as for `KM`, which is then its
+value in terms of the units it is made of. The formula only gives the units, so a `Kilometer` is a
+`Meter` with a different name, and using `Kilometer` in another formula is the same as using `Meter`.
+As the value of a quantity is always stored in base units, the symbol of a scaled unit is never
+used to print a quantity, and scaled units are left out of the integer constant modules. Printing
+never rescales the value, as that would need more of the value type than `Display`, so
+`2.0 * ucum::N` prints as `2000 mN`; the `fmt::Engineering` adaptor picks a prefix for the value
+instead, and prints it as `2 N`.
+
+Finally, we again end with an optional dimension. Note that there is none present for the `M5`
+line. A scaled unit will usually share its type with another unit, in which case only one of them
+may have a given dimension.
 
 ```ignore
         derived {
             MPS: MeterPerSecond = (Meter / Second), Velocity;
-            HZ: Hertz = (Unitless / Second), Frequency;
+            HZ: Hertz, "Hz" = (Unitless / Second), Frequency;
+
+            M5: Meter5 = (Meter3 * Meter^2);
+            M3: Meter3 = (Meter^3), Volume;
+            PM5: PerMeter5 = ((Meter / Unitless)^-5);
 
-            M3: Meter3 = (Meter * Meter * Meter), Volume;
-            M5: Meter5 = (Meter3 * Meter * Meter);
+            KM: Kilometer, "km" = (Meter) * 1000.0;
         }
 
 ```
 
 In the `constants` block, we can define constants of whatever values we wish. Note that the
-constants in the `base` and `derived` blocks are always created with a value of 1.0.
+constants in the `base` block, and in the `derived` block unless given a scale, are created with a
+value of 1.0.
 
 All constants are created in both `f32` and `f64` flavors, in the submodules `f32consts` and
 `f64consts`, respectively.
 
-In addition, the modules for all integer constants are created. However, these only include
-constants for base and derived units. The full list of integer modules is `i8consts`, `i16consts`,
-`i32consts`, `i64consts`, `isize_consts`, `u8consts`, `u16consts`, `u32consts`, `u64consts`,
-`usize_consts`.
+In addition, the modules for all integer constants are created. These include constants for base
+units and for derived units without a scale. The full list of integer modules is `i8consts`,
+`i16consts`, `i32consts`, `i64consts`, `isize_consts`, `u8consts`, `u16consts`, `u32consts`,
+`u64consts`, `usize_consts`.
 
-If you would like non-unary integer constants, you will have to construct them yourself, like so
+A constant followed by `, integer`, like `MIN` and `HR`, is also created in `i32consts`,
+`i64consts`, `u32consts` and `u64consts`, so that `ms::u32consts::HR` is `Second::new(3600)`. To
+create it in other integer modules, list their types instead, as in `, integer(i64, u64)` for a
+constant that doesn't fit in 32 bits, or `, integer(i8, i16, i32, i64, isize)` for a negative one.
+Its value is computed as for `f64consts`, and it is a compile error if that isn't an integer that
+fits in each of those types:
 
-```rust
+```rust,compile_fail,E0080
+# #[macro_use]
 # extern crate dimensioned as dim;
-# use std::marker::PhantomData;
-use dim::si;
-const MIN: si::Second<u32> = si::Second::new(60);
+# mod ms {
+make_units! {
+    MS;
+    ONE: Unitless;
+    base {
+        M: Meter, "m", Length;
+    }
+    derived {}
+    constants {
+        FT: Meter = 0.3048, integer;
+    }
+    fmt = true;
+}
+# }
 # fn main() {}
 ```
 
-Support for making this better is in the works.
-
 In these submodules, the consts from the respective version of `f32prefixes` or `f64prefixes` are in
 scope, hence the use of `CENTI` in the `CM` definition.
 
@@ -139,20 +176,29 @@ allows the use of `consts::PI` in the `PI definition.
             FT: Meter = 0.3048;
             CM: Meter = CENTI * M.value_unsafe;
 
-            MIN: Second = 60.0;
-            HR: Second = 60.0 * MIN.value_unsafe;
+            MIN: Second = 60.0, integer;
+            HR: Second = 60.0 * MIN.value_unsafe, integer;
 
             PI: Unitless = consts::PI;
         }
 ```
 
-Finally, we have the `fmt` line. This line can either be `fmt = true;` or `fmt = false;`. In either
-case, the trait `core::fmt::Debug` is implemented for your unit system, but all of the other `fmt`
-traits are implemented only if this is true. Setting it to `false` allows you to have custom
-printing for your system.
+Next, we have the `fmt` line. This line can either be `fmt = true;` or `fmt = false;`. In either
+case, the trait `core::fmt::Debug` is implemented for your unit system, always printing units in
+terms of base units, but all of the other `fmt` traits are implemented only if this is true, as are
+`dim::fmt::FmtUnits` and `core::str::FromStr`, which parses the format that `Display` emits. Setting
+it to `false` allows you to have custom printing (and parsing) for your system.
 
 ```ignore
         fmt = true;
+```
+
+Finally, there is an optional `symbols` line, naming a table of `dim::parse::Symbol`s. If present,
+`FromStr` will also accept those symbols, with SI prefixes, in the units of a string, scaling the
+value accordingly. See the `parse` module for more information. We don't use it in our example.
+
+```ignore
+        symbols = SYMBOLS;
     }
 ```
 
@@ -182,12 +228,15 @@ macro_rules! make_units {
          $($base:ident: $Unit:ident, $print_as:expr $(, $base_dim:ident)*;)+
      }
      derived {
-         $($derived_const:ident: $Derived:ident = ($($derived_rhs:tt)+) $(, $derived_dim:ident)*;)*
+         $($derived_const:ident: $Derived:ident $(, $derived_print:literal)? = ($($derived_rhs:tt)+)
+           $(* $derived_scale:expr)? $(, $derived_dim:ident)*;)*
      }
      constants {
-         $($constant:ident: $ConstantUnit:ident = $constant_value:expr;)*
+         $($constant:ident: $ConstantUnit:ident = $constant_value:expr
+           $(, $constant_flag:ident $(($($constant_int:ident),+))?)?;)*
      }
      fmt = $to_fmt:ident;
+     $(symbols = $symbols:path;)?
     ) => (
         use $crate::dimcore::marker::PhantomData;
         use $crate::{Dimensioned, Dimensionless};
@@ -213,6 +262,28 @@ macro_rules! make_units {
             pub const fn new(v: V) -> Self {
                 $System { value_unsafe: v, _marker: PhantomData }
             }
+
+            /// Get the value of this quantity in terms of `unit`, a quantity of the same type, as
+            /// in `distance.value_in(MI)`. As `unit` must have the same units as `self`, this is
+            /// dimensionally safe.
+            #[inline]
+            pub fn value_in(self, unit: Self) -> <V as $crate::dimcore::ops::Div>::Output
+            where
+                V: $crate::dimcore::ops::Div,
+            {
+                self.value_unsafe / unit.value_unsafe
+            }
+
+            /// Display this quantity in terms of `unit`, a quantity of the same type, followed by
+            /// `label` rather than units. See `dim::fmt::DisplayIn` for an example.
+            #[inline]
+            pub fn display_in(self, unit: Self, label: &str)
+                -> $crate::fmt::DisplayIn<'_, <V as $crate::dimcore::ops::Div>::Output>
+            where
+                V: $crate::dimcore::ops::Div,
+            {
+                $crate::fmt::DisplayIn::new(self.value_in(unit), label)
+            }
         }
 
         impl<V, U> $System<V, U>
@@ -220,51 +291,54 @@ macro_rules! make_units {
             Length<U>: ArrayLength<isize>,
             U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
         {
-            /// Format just the units of this type.
+            /// Format just the units of this type, in terms of base units.
             #[inline]
-            fn fmt_units(f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+            fn fmt_base_tokens(f: &mut fmt::Formatter, style: $crate::fmt::Style) -> fmt::Result
             {
                 let exponents = U::to_ga();
                 let print_tokens = [$($print_as),+];
+                $crate::fmt::write_units(
+                    f, style, print_tokens.iter().cloned().zip(exponents.into_iter()), 1)
+            }
+        }
 
-                fn write_unit(f: &mut fmt::Formatter, exp: isize, token: &str) -> Result<(), fmt::Error> {
-                    if exp == 1 {
-                        write!(f, "{}", token)
-                    } else {
-                        write!(f, "{}^{}", token, exp)
+        impl<V, U> $crate::fmt::WriteUnits for $System<V, U>
+        where
+            Self: $crate::fmt::FmtUnits,
+            U: $crate::array::ToArray<{ inner::BASE_UNITS }>,
+        {
+            const EXPONENTS: &'static [isize] = &U::ARRAY;
+
+            fn write_units<W: fmt::Write>(w: &mut W) -> fmt::Result {
+                struct Displayer<T>(PhantomData<T>);
+                impl<T: $crate::fmt::FmtUnits> fmt::Display for Displayer<T> {
+                    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        T::fmt_units(f, $crate::fmt::Style::Ascii)
                     }
                 }
 
-                let mut units = exponents.into_iter()
-                    .zip(print_tokens.iter()).filter(|(exp, _)| *exp != 0);
-                if let Some((exp, token)) = units.next() {
-                    write_unit(f, exp, token)?;
-                }
-                for (exp, token) in units {
-                    write!(f, "*")?;
-                    write_unit(f, exp, token)?;
-                }
-                Ok(())
+                write!(w, "{}", Displayer::<Self>(PhantomData))
             }
+        }
 
+        impl<V, U> $System<V, U> {
             /// Output the units of this type in the same format as in the
             /// formatting traits.
             #[inline]
             #[cfg(feature = "std")]
-            pub fn to_string() -> String {
-                struct Displayer<U2>(PhantomData<U2>);
-                impl<U2> fmt::Display for Displayer<U2>
-                where
-                    Length<U2>: ArrayLength<isize>,
-                    U2: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U2>>>,
-                {
+            pub fn to_string() -> String
+            where
+                Self: $crate::fmt::FmtUnits,
+            {
+                struct Displayer<T>(PhantomData<T>);
+                impl<T: $crate::fmt::FmtUnits> fmt::Display for Displayer<T> {
                     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
                     {
-                        <$System<f32, U2>>::fmt_units(f)
+                        T::fmt_units(f, $crate::fmt::Style::Ascii)
                     }
                 }
 
-                format!("{}", Displayer::<U>(PhantomData))
+                format!("{}", Displayer::<Self>(PhantomData))
             }
         }
 
@@ -321,8 +395,16 @@ macro_rules! make_units {
             #[allow(unused_imports)]
             use $crate::typenum::consts::*;
             __make_units_internal!(@base_arrays $Unitless $($Unit)+);
+            pub const BASE_UNITS: usize = __make_units_internal!(@count $($Unit)+);
             $(#[allow(missing_docs)] pub type $Derived =
-              __derived_internal!(@mu commas $($derived_rhs)+);)*
+              __derived_internal!(@mu expr $($derived_rhs)+);)*
+
+            /// Whether each derived unit has a value of 1, so that it may be printed with its
+            /// symbol.
+            pub mod unit_valued {
+                $(#[allow(non_upper_case_globals)]
+                  pub const $Derived: bool = __make_units_internal!(@unit_valued $($derived_scale)?);)*
+            }
         }
 
         #[allow(missing_docs)]
@@ -359,9 +441,11 @@ macro_rules! make_units {
                     $(#[allow(dead_code, missing_docs)]
                       pub const $base: $Unit<$t> =
                           $System { value_unsafe: 1.0, _marker: PhantomData };)+
-                    $(#[allow(dead_code, missing_docs)]
-                      pub const $derived_const: $Derived<$t> =
-                          $System { value_unsafe: 1.0, _marker: PhantomData };)*
+                    $(#[allow(dead_code, missing_docs, clippy::unnecessary_cast)]
+                      pub const $derived_const: $Derived<$t> = $System {
+                          value_unsafe: __make_units_internal!(@scale $t $($derived_scale)?),
+                          _marker: PhantomData,
+                      };)*
                     $(#[allow(dead_code, missing_docs)]
                       pub const $constant: $ConstantUnit<$t> =
                           $System { value_unsafe: $constant_value, _marker: PhantomData };)*
@@ -383,9 +467,11 @@ macro_rules! make_units {
                     $(#[allow(dead_code, missing_docs)]
                       pub const $base: $Unit<$t> =
                           $System { value_unsafe: 1, _marker: PhantomData };)+
-                    $(#[allow(dead_code, missing_docs)]
-                      pub const $derived_const: $Derived<$t> =
-                          $System { value_unsafe: 1, _marker: PhantomData };)*
+                    $(__make_units_internal!(
+                        @int_derived $System $t $derived_const $Derived $($derived_scale)?);)*
+                    $(__make_units_internal!(
+                        @int_constant $System $t $constant $ConstantUnit
+                        $($constant_flag $(($($constant_int),+))?)?);)*
                 }
             );
         }
@@ -409,7 +495,25 @@ macro_rules! make_units {
         use $crate::generic_array::{GenericArray, ArrayLength};
         use $crate::array::ToGA;
 
-        __make_units_internal!(@fmt true S $System $(P $print_as;)+ T Debug E "{:?}");
+        // Debug always writes units in terms of base units
+        impl<V, U> fmt::Debug for $System<V, U> where
+            V: fmt::Debug,
+            Length<U>: ArrayLength<isize>,
+            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
+            {
+                self.value_unsafe.fmt(f)?;
+                if U::to_ga().iter().any(|&exp| exp != 0) {
+                    write!(f, " ")?;
+                    Self::fmt_base_tokens(f, $crate::fmt::Style::Ascii)?;
+                }
+                Ok(())
+            }
+        }
+
+        __make_units_internal!(@fmt_units $to_fmt S $System $(P $print_as;)+
+                               $($(D $Derived $derived_print;)?)*);
         __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)+ T Display E "{}");
         __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)+ T Octal E "{:o}");
         __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)+ T LowerHex E "{:x}");
@@ -419,6 +523,187 @@ macro_rules! make_units {
         __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)+ T LowerExp E "{:e}");
         __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)+ T UpperExp E "{:E}");
 
+        // --------------------------------------------------------------------------------
+        // Parsing
+
+        __make_units_internal!(@from_str $to_fmt S $System Y $($symbols)?; $(P $print_as;)+
+                               $($(D $Derived $derived_print;)?)*);
+
+        // --------------------------------------------------------------------------------
+        // Runtime-dimensioned quantities
+
+        /// A quantity in this unit system whose units are only known at runtime. See the
+        /// `dynamic` module for more information.
+        #[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
+        pub struct Dynamic<V> {
+            value: V,
+            exponents: [isize; inner::BASE_UNITS],
+        }
+
+        impl<V> Dynamic<V> {
+            /// Create a new quantity with the given power of each base unit, in the order in which
+            /// the base units are defined.
+            #[inline]
+            pub const fn new(value: V, exponents: [isize; inner::BASE_UNITS]) -> Self {
+                Dynamic { value, exponents }
+            }
+
+            /// The value of this quantity.
+            #[inline]
+            pub fn value(&self) -> &V {
+                &self.value
+            }
+
+            /// The power of each base unit of this quantity.
+            #[inline]
+            pub fn exponents(&self) -> &[isize; inner::BASE_UNITS] {
+                &self.exponents
+            }
+
+            /// Whether this quantity is dimensionless.
+            #[inline]
+            pub fn is_dimensionless(&self) -> bool {
+                self.exponents.iter().all(|&exp| exp == 0)
+            }
+
+            /// Add `rhs` to this quantity, if their units match.
+            #[inline]
+            pub fn checked_add<Vr>(self, rhs: Dynamic<Vr>)
+                -> Result<Dynamic<<V as $crate::dimcore::ops::Add<Vr>>::Output>, $crate::dynamic::DimensionMismatch>
+            where
+                V: $crate::dimcore::ops::Add<Vr>,
+            {
+                if self.exponents == rhs.exponents {
+                    Ok(Dynamic::new(self.value + rhs.value, self.exponents))
+                } else {
+                    Err($crate::dynamic::DimensionMismatch)
+                }
+            }
+
+            /// Subtract `rhs` from this quantity, if their units match.
+            #[inline]
+            pub fn checked_sub<Vr>(self, rhs: Dynamic<Vr>)
+                -> Result<Dynamic<<V as $crate::dimcore::ops::Sub<Vr>>::Output>, $crate::dynamic::DimensionMismatch>
+            where
+                V: $crate::dimcore::ops::Sub<Vr>,
+            {
+                if self.exponents == rhs.exponents {
+                    Ok(Dynamic::new(self.value - rhs.value, self.exponents))
+                } else {
+                    Err($crate::dynamic::DimensionMismatch)
+                }
+            }
+
+            /// Raise this quantity to the integer power `exp`, if the power of each base unit
+            /// doesn't overflow.
+            #[inline]
+            pub fn checked_powi(self, exp: i32) -> Option<Self>
+            where
+                V: Clone + $crate::num_traits::One + $crate::dimcore::ops::Div<Output = V>,
+            {
+                let exp_units: isize = $crate::dimcore::convert::TryFrom::try_from(exp).ok()?;
+                let mut exponents = self.exponents;
+                for e in exponents.iter_mut() {
+                    *e = e.checked_mul(exp_units)?;
+                }
+                let value = $crate::num_traits::pow(self.value, exp.unsigned_abs() as usize);
+                let value = if exp < 0 { V::one() / value } else { value };
+                Some(Dynamic::new(value, exponents))
+            }
+
+            /// Raise this quantity to the integer power `exp`.
+            ///
+            /// # Panics
+            /// If the power of a base unit overflows.
+            #[inline]
+            pub fn powi(self, exp: i32) -> Self
+            where
+                V: Clone + $crate::num_traits::One + $crate::dimcore::ops::Div<Output = V>,
+            {
+                self.checked_powi(exp).expect("attempted to raise units to a power that overflows")
+            }
+        }
+
+        impl<Vl, Vr> $crate::dimcore::ops::Add<Dynamic<Vr>> for Dynamic<Vl>
+            where Vl: $crate::dimcore::ops::Add<Vr>,
+        {
+            type Output = Dynamic<<Vl as $crate::dimcore::ops::Add<Vr>>::Output>;
+
+            /// # Panics
+            /// If the units of `self` and `rhs` don't match.
+            #[inline]
+            fn add(self, rhs: Dynamic<Vr>) -> Self::Output {
+                self.checked_add(rhs).expect("attempted to add quantities with different units")
+            }
+        }
+
+        impl<Vl, Vr> $crate::dimcore::ops::Sub<Dynamic<Vr>> for Dynamic<Vl>
+            where Vl: $crate::dimcore::ops::Sub<Vr>,
+        {
+            type Output = Dynamic<<Vl as $crate::dimcore::ops::Sub<Vr>>::Output>;
+
+            /// # Panics
+            /// If the units of `self` and `rhs` don't match.
+            #[inline]
+            fn sub(self, rhs: Dynamic<Vr>) -> Self::Output {
+                self.checked_sub(rhs).expect("attempted to subtract quantities with different units")
+            }
+        }
+
+        impl<Vl, Vr> $crate::dimcore::ops::Mul<Dynamic<Vr>> for Dynamic<Vl>
+            where Vl: $crate::dimcore::ops::Mul<Vr>,
+        {
+            type Output = Dynamic<<Vl as $crate::dimcore::ops::Mul<Vr>>::Output>;
+
+            #[inline]
+            fn mul(self, rhs: Dynamic<Vr>) -> Self::Output {
+                let mut exponents = self.exponents;
+                for (e, r) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+                    *e += r;
+                }
+                Dynamic::new(self.value * rhs.value, exponents)
+            }
+        }
+
+        impl<Vl, Vr> $crate::dimcore::ops::Div<Dynamic<Vr>> for Dynamic<Vl>
+            where Vl: $crate::dimcore::ops::Div<Vr>,
+        {
+            type Output = Dynamic<<Vl as $crate::dimcore::ops::Div<Vr>>::Output>;
+
+            #[inline]
+            fn div(self, rhs: Dynamic<Vr>) -> Self::Output {
+                let mut exponents = self.exponents;
+                for (e, r) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+                    *e -= r;
+                }
+                Dynamic::new(self.value / rhs.value, exponents)
+            }
+        }
+
+        impl<V, U> From<$System<V, U>> for Dynamic<V>
+            where U: $crate::array::ToArray<{ inner::BASE_UNITS }>,
+        {
+            #[inline]
+            fn from(quantity: $System<V, U>) -> Self {
+                Dynamic::new(quantity.value_unsafe, U::ARRAY)
+            }
+        }
+
+        impl<V, U> $crate::dimcore::convert::TryFrom<Dynamic<V>> for $System<V, U>
+            where U: $crate::array::ToArray<{ inner::BASE_UNITS }>,
+        {
+            type Error = $crate::dynamic::DimensionMismatch;
+
+            #[inline]
+            fn try_from(quantity: Dynamic<V>) -> Result<Self, Self::Error> {
+                if quantity.exponents == U::ARRAY {
+                    Ok($System::new(quantity.value))
+                } else {
+                    Err($crate::dynamic::DimensionMismatch)
+                }
+            }
+        }
+
         // --------------------------------------------------------------------------------
         // Operator traits from this crate
 
@@ -1012,15 +1297,14 @@ macro_rules! __make_units_internal {
     (@fmt true S $System:ident $(P $print_as:expr;)* T $Trait:ident E $token:expr) => (
         impl<V, U> fmt::$Trait for $System<V, U> where
             V: fmt::$Trait,
-        Length<U>: ArrayLength<isize>,
-            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+            Self: $crate::fmt::FmtUnits,
         {
             fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error>
             {
                 self.value_unsafe.fmt(f)?;
-                if U::to_ga().iter().any(|&exp| exp != 0) {
+                if !<Self as $crate::fmt::FmtUnits>::is_unitless() {
                     write!(f, " ")?;
-                    Self::fmt_units(f)?;
+                    <Self as $crate::fmt::FmtUnits>::fmt_units(f, $crate::fmt::Style::Ascii)?;
                 }
                 Ok(())
             }
@@ -1029,6 +1313,197 @@ macro_rules! __make_units_internal {
 
     (@fmt false S $System:ident $(P $print_as:expr;)* T $Trait:ident E $token:expr) => ();
 
+    (@fmt_units true S $System:ident $(P $print_as:expr;)* $(D $Derived:ident $derived_print:expr;)*) => (
+        impl<V, U> $crate::fmt::FmtUnits for $System<V, U> where
+            Length<U>: ArrayLength<isize>,
+            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+        {
+            fn is_unitless() -> bool {
+                U::to_ga().iter().all(|&exp| exp == 0)
+            }
+
+            fn unit_symbol() -> Option<&'static str> {
+                let exponents = U::to_ga();
+                // a base unit is always written with its own token
+                let mut units = exponents.iter().zip([$($print_as),*].iter())
+                    .filter(|&(&exp, _)| exp != 0);
+                if let (Some((&1, &token)), None) = (units.next(), units.next()) {
+                    return Some(token);
+                }
+                $(if inner::unit_valued::$Derived && exponents[..] == inner::$Derived::to_ga()[..] {
+                    return Some($derived_print);
+                })*
+                None
+            }
+
+            fn fmt_base_units(f: &mut fmt::Formatter, style: $crate::fmt::Style) -> fmt::Result {
+                Self::fmt_base_tokens(f, style)
+            }
+        }
+    );
+
+    (@fmt_units false S $System:ident $(P $print_as:expr;)* $(D $Derived:ident $derived_print:expr;)*) => ();
+
+    (@from_str true S $System:ident Y ; $(P $print_as:expr;)*
+     $(D $Derived:ident $derived_print:expr;)*) => (
+        impl<V, U> $crate::dimcore::str::FromStr for $System<V, U> where
+            V: $crate::dimcore::str::FromStr,
+        Length<U>: ArrayLength<isize>,
+            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+        {
+            type Err = $crate::parse::ParseQuantityError<V::Err>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (value, units) = $crate::parse::split_quantity(s);
+                let value = V::from_str(value).map_err($crate::parse::ParseQuantityError::Value)?;
+
+                let tokens = [$($print_as),*];
+                let lookup = |name: &str, ignore_case: bool| {
+                    if ignore_case {
+                        return None;
+                    }
+                    if let Some(i) = tokens.iter().position(|&t| t == name) {
+                        let mut exponents = GenericArray::<isize, Length<U>>::default();
+                        exponents[i] = 1;
+                        return Some((1.0, exponents));
+                    }
+                    $(if inner::unit_valued::$Derived && name == $derived_print {
+                        let mut exponents = GenericArray::<isize, Length<U>>::default();
+                        exponents.copy_from_slice(&inner::$Derived::to_ga());
+                        return Some((1.0, exponents));
+                    })*
+                    None
+                };
+
+                let mut exponents = GenericArray::<isize, Length<U>>::default();
+                $crate::parse::parse_expression(units, &mut exponents, false, &lookup)?;
+                if exponents != U::to_ga() {
+                    return Err($crate::parse::ParseQuantityError::UnitMismatch);
+                }
+
+                Ok($System::new(value))
+            }
+        }
+    );
+
+    (@from_str true S $System:ident Y $symbols:path; $(P $print_as:expr;)*
+     $(D $Derived:ident $derived_print:expr;)*) => (
+        impl<V, U> $crate::dimcore::str::FromStr for $System<V, U> where
+            V: $crate::dimcore::str::FromStr + Mul<Output = V> + $crate::num_traits::FromPrimitive
+                + $crate::num_traits::ToPrimitive,
+        Length<U>: ArrayLength<isize>,
+            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+        {
+            type Err = $crate::parse::ParseQuantityError<V::Err>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (value, units) = $crate::parse::split_quantity(s);
+                let value = V::from_str(value).map_err($crate::parse::ParseQuantityError::Value)?;
+
+                let lookup = |name: &str, ignore_case: bool| {
+                    $crate::parse::find_symbol($symbols, name, ignore_case)
+                        .map(|symbol| (symbol.value, symbol.exponents))
+                };
+
+                let mut exponents = GenericArray::<isize, Length<U>>::default();
+                let factor = $crate::parse::parse_expression(units, &mut exponents, true, &lookup)?;
+                if exponents != U::to_ga() {
+                    return Err($crate::parse::ParseQuantityError::UnitMismatch);
+                }
+                let factor = $crate::parse::convert_factor(factor)?;
+
+                Ok($System::new(value * factor))
+            }
+        }
+    );
+
+    (@from_str false S $System:ident Y $($symbols:path)?; $(P $print_as:expr;)*
+     $(D $Derived:ident $derived_print:expr;)*) => ();
+
+    // the values of derived units
+    (@unit_valued) => (true);
+    (@unit_valued $scale:expr) => (false);
+    (@scale $t:ident) => (1.0);
+    (@scale $t:ident $scale:expr) => ($scale as $t);
+
+    // derived units that don't have a value of 1 are left out of the integer constants
+    (@int_derived $System:ident $t:ident $derived_const:ident $Derived:ident) => (
+        #[allow(dead_code, missing_docs)]
+        pub const $derived_const: $Derived<$t> =
+            $System { value_unsafe: 1, _marker: PhantomData };
+    );
+    (@int_derived $System:ident $t:ident $derived_const:ident $Derived:ident $scale:expr) => ();
+
+    // constants marked `integer` are defined for the 32 and 64 bit integer types, or for those
+    // listed, as in `integer(i64, u64)`, and must be exact in them
+    (@int_constant $System:ident $t:ident $constant:ident $Unit:ident) => ();
+    (@int_constant $System:ident $t:ident $constant:ident $Unit:ident integer) => (
+        __make_units_internal!(@int_constant $System $t $constant $Unit integer(i32, i64, u32, u64));
+    );
+    (@int_constant $System:ident $t:ident $constant:ident $Unit:ident integer($($ty:ident),+)) => (
+        __make_units_internal!(@int_constant_in $System $t $constant $Unit [$($ty)+]);
+    );
+    (@int_constant $System:ident $t:ident $constant:ident $Unit:ident $flag:ident $($types:tt)*) => (
+        compile_error!(concat!("unknown flag ", stringify!($flag), " for constant ",
+                               stringify!($constant)));
+    );
+
+    // define the constant if `$t` is in the list of types
+    (@int_constant_in $System:ident $t:ident $constant:ident $Unit:ident []) => ();
+    (@int_constant_in $System:ident i8 $constant:ident $Unit:ident [i8 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System i8 $constant $Unit);
+    );
+    (@int_constant_in $System:ident i16 $constant:ident $Unit:ident [i16 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System i16 $constant $Unit);
+    );
+    (@int_constant_in $System:ident i32 $constant:ident $Unit:ident [i32 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System i32 $constant $Unit);
+    );
+    (@int_constant_in $System:ident i64 $constant:ident $Unit:ident [i64 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System i64 $constant $Unit);
+    );
+    (@int_constant_in $System:ident isize $constant:ident $Unit:ident [isize $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System isize $constant $Unit);
+    );
+    (@int_constant_in $System:ident u8 $constant:ident $Unit:ident [u8 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System u8 $constant $Unit);
+    );
+    (@int_constant_in $System:ident u16 $constant:ident $Unit:ident [u16 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System u16 $constant $Unit);
+    );
+    (@int_constant_in $System:ident u32 $constant:ident $Unit:ident [u32 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System u32 $constant $Unit);
+    );
+    (@int_constant_in $System:ident u64 $constant:ident $Unit:ident [u64 $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System u64 $constant $Unit);
+    );
+    (@int_constant_in $System:ident usize $constant:ident $Unit:ident [usize $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_value $System usize $constant $Unit);
+    );
+    (@int_constant_in $System:ident $t:ident $constant:ident $Unit:ident [$ty:ident $($rest:ident)*]) => (
+        __make_units_internal!(@int_constant_in $System $t $constant $Unit [$($rest)*]);
+    );
+    (@int_constant_value $System:ident $t:ident $constant:ident $Unit:ident) => (
+        #[allow(dead_code, missing_docs)]
+        pub const $constant: $Unit<$t> = $System {
+            value_unsafe: {
+                let value = super::f64consts::$constant.value_unsafe;
+                let int = value as $t;
+                assert!(
+                    int as f64 == value,
+                    concat!("the constant ", stringify!($constant), " is not an integer that fits in ",
+                            stringify!($t))
+                );
+                int
+            },
+            _marker: PhantomData,
+        };
+    );
+
+    // count the base units
+    (@count) => (0);
+    (@count $first:ident $($rest:ident)*) => (1 + __make_units_internal!(@count $($rest)*));
+
     // define arrays for all the base units
     (@base_arrays $Unitless:ident $Unit:ident $($Units:ident)*) => (
         pub type $Unitless = tarr![Z0, $(__make_units_internal!(@convert_to_zero $Units)),*];
@@ -1160,13 +1635,45 @@ macro_rules! __derived_internal {
     //------------------------------------------
     // For make_units:
 
-    (@mu eval $a:ty,) => ($a);
-    (@mu eval $a:ty, *, $b:ty, $($tail:tt)*) =>
-        (__derived_internal!(@mu eval $crate::typenum::Sum<$a, $b>, $($tail)* ));
-    (@mu eval $a:ty, /, $b:ty, $($tail:tt)*) =>
-        (__derived_internal!(@mu eval $crate::typenum::Diff<$a, $b>, $($tail)* ));
-    (@mu commas $t:ty) => ($t);
-    (@mu commas $($tail:tt)*) => (__derived_internal!(@mu eval $($tail,)*));
+    // An expression is a chain of factors separated by `*` and `/`, where each factor is a unit or
+    // a parenthesized expression, optionally raised to an integer power with `^`.
+    (@mu expr $($tail:tt)+) => (__derived_internal!(@mu factor [] $($tail)+));
+
+    (@mu factor $acc:tt $a:ident $($tail:tt)*) => (
+        __derived_internal!(@mu pow $acc ($a) $($tail)*)
+    );
+    (@mu factor $acc:tt ($($inner:tt)+) $($tail:tt)*) => (
+        __derived_internal!(@mu pow $acc (__derived_internal!(@mu expr $($inner)+)) $($tail)*)
+    );
+
+    (@mu pow $acc:tt ($($a:tt)+) ^ - $n:tt $($tail:tt)*) => (
+        __derived_internal!(
+            @mu combine $acc
+            ($crate::typenum::Prod<$($a)+, __derived_internal!(@mu neg $n)>) $($tail)*
+        )
+    );
+    (@mu pow $acc:tt ($($a:tt)+) ^ $n:tt $($tail:tt)*) => (
+        __derived_internal!(
+            @mu combine $acc
+            ($crate::typenum::Prod<$($a)+, __derived_internal!(@mu pos $n)>) $($tail)*
+        )
+    );
+    (@mu pow $acc:tt $a:tt $($tail:tt)*) => (__derived_internal!(@mu combine $acc $a $($tail)*));
+
+    (@mu combine [] $a:tt $($tail:tt)*) => (__derived_internal!(@mu op $a $($tail)*));
+    (@mu combine [$Op:ident ($($acc:tt)+)] ($($a:tt)+) $($tail:tt)*) => (
+        __derived_internal!(@mu op ($crate::typenum::$Op<$($acc)+, $($a)+>) $($tail)*)
+    );
+
+    (@mu op ($($acc:tt)+)) => ($($acc)+);
+    (@mu op $acc:tt * $($tail:tt)+) => (__derived_internal!(@mu factor [Sum $acc] $($tail)+));
+    (@mu op $acc:tt / $($tail:tt)+) => (__derived_internal!(@mu factor [Diff $acc] $($tail)+));
+
+    // Integer powers, as type-level integers.
+    (@mu pos 0) => ($crate::typenum::Z0);
+    (@mu pos $n:tt) => ($crate::typenum::PInt<$crate::typenum::U<$n>>);
+    (@mu neg 0) => ($crate::typenum::Z0);
+    (@mu neg $n:tt) => ($crate::typenum::NInt<$crate::typenum::U<$n>>);
 }
 
 /// Implement rand traits for a unit system.
@@ -1251,6 +1758,9 @@ macro_rules! impl_rand {
 /// ```rust,ignore
 /// impl_serde!(UnitSystem);
 /// ```
+///
+/// As only the value is serialized, its units are not checked when deserializing; for that, see
+/// `serde_helpers::tagged`.
 #[cfg(feature = "serde")]
 #[macro_export]
 macro_rules! impl_serde {
@@ -1287,6 +1797,56 @@ macro_rules! impl_serde {
     };
 }
 
+/// Create a module for serializing quantities as bare values in terms of a given unit.
+///
+/// The module is for use with serde's `with` attribute. It is given the type of the quantities it
+/// is for, and the unit in which to serialize them, which is any expression of that type, e.g.
+/// `si::MIN` or `MILLI * si::S`. The value in terms of that unit is serialized and deserialized as
+/// the value type would be.
+///
+/// ```rust,ignore
+/// use dim::si;
+/// use dim::f64prefixes::MILLI;
+///
+/// serde_in!(mod milliseconds: si::Second<f64> = MILLI * si::S);
+/// serde_in!(pub mod psi: si::Pascal<f64> = si::PSI);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Message {
+///     #[serde(with = "milliseconds")]
+///     timeout: si::Second<f64>,
+///     #[serde(with = "psi")]
+///     pressure: si::Pascal<f64>,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! serde_in {
+    ($(#[$attr:meta])* $vis:vis mod $name:ident: $Quantity:ty = $unit:expr) => {
+        $(#[$attr])*
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// Serialize a quantity as its value in terms of the unit of this module.
+            pub fn serialize<S>(quantity: &$Quantity, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                $crate::serde_helpers::serialize_in(quantity, &($unit), serializer)
+            }
+
+            /// Deserialize a quantity from its value in terms of the unit of this module.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<$Quantity, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                $crate::serde_helpers::deserialize_in(&($unit), deserializer)
+            }
+        }
+    };
+}
+
 /// Implement ClapMe trait for a unit system.
 ///
 /// ```rust,ignore
@@ -1330,8 +1890,7 @@ macro_rules! impl_auto_args {
     ($System:ident) => {
         impl<V: auto_args::AutoArgs, U> auto_args::AutoArgs for $System<V, U>
         where
-            Length<U>: ArrayLength<isize>,
-            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+            Self: $crate::fmt::FmtUnits,
         {
             fn parse_internal(
                 key: &str,
@@ -0,0 +1,428 @@
+//! Parsing quantities from strings
+//!
+//! Every unit system created with `make_units!` (with `fmt = true`) implements
+//! `core::str::FromStr`, accepting at least the format that its `Display` implementation emits;
+//! that is, the value, followed by a single space and the units of the quantity if it has any.
+//!
+//! The units are an expression built from unit symbols with the operators `*`, `/` and `^`, and
+//! parentheses. Without a symbol table, the only symbols are the print tokens of the base units
+//! and the print symbols of derived units.
+//!
+//! A unit system may also be given a table of [`Symbol`](struct.Symbol.html)s, in which case the
+//! names of its derived units and constants, and the SI prefixes from `f64prefixes` applied to any
+//! of them, may also be used. The value is then scaled by the value of the symbols, so the value
+//! type must implement `num_traits::FromPrimitive` and `num_traits::ToPrimitive`; if it can't
+//! represent the scale factor, as for `"1500 mm"` with an integer value type, parsing fails. The
+//! `SI` and `UCUM` systems have such a table, named `SYMBOLS`.
+//!
+//! Symbols are first matched exactly, then with a prefix, and only then ignoring ASCII case (for
+//! symbols written in capitals, such as constant names), with or without a prefix, so `"ns"` is a
+//! nanosecond, while `"NS"` and `"Ns"` are newton seconds and `"keV"` is a kiloelectronvolt. The
+//! prefix micro may be written as `µ`, `μ` or `u`.
+//!
+//! Parsing fails if the units in the string do not match the units of the type being parsed, so
+//! the units of a quantity are checked even when it comes from text.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::si;
+//!
+//! fn main() {
+//!     let f: si::Newton<f64> = "3.5 m*kg*s^-2".parse().unwrap();
+//!     assert_eq!(f, 3.5 * si::N);
+//!
+//!     let f: si::Newton<f64> = "3.5 kN".parse().unwrap();
+//!     assert_eq!(f, 3500.0 * si::N);
+//!
+//!     let t: si::Second<f64> = "5 MIN".parse().unwrap();
+//!     assert_eq!(t, 300.0 * si::S);
+//!
+//!     let a: si::MeterPerSecond2<f64> = "3 km/(min*s)".parse().unwrap();
+//!     assert_eq!(a, 50.0 * si::MPS2);
+//!
+//!     // Wrong units for the type:
+//!     assert!("3.5 m*kg*s^-2".parse::<si::Joule<f64>>().is_err());
+//! }
+//! ```
+
+use core::fmt;
+use num_traits::float::FloatCore;
+
+/// The error returned when parsing a quantity from a string fails.
+///
+/// The parameter `E` is the error type of parsing the value itself; e.g. for `si::Meter<f64>`, it
+/// is `core::num::ParseFloatError`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseQuantityError<E> {
+    /// The value could not be parsed.
+    Value(E),
+    /// The string contains a unit that is not part of the unit system.
+    UnknownUnit,
+    /// An exponent in the string could not be parsed.
+    InvalidExponent,
+    /// The units expression is malformed, e.g. it has unbalanced parentheses.
+    MalformedUnits,
+    /// The units in the string are valid, but are not the units of the type being parsed.
+    UnitMismatch,
+    /// The scale factor of the units in the string cannot be represented by the value type.
+    OutOfRange,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseQuantityError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseQuantityError::Value(ref e) => write!(f, "invalid value: {}", e),
+            ParseQuantityError::UnknownUnit => write!(f, "unknown unit"),
+            ParseQuantityError::InvalidExponent => write!(f, "invalid unit exponent"),
+            ParseQuantityError::MalformedUnits => write!(f, "malformed units expression"),
+            ParseQuantityError::UnitMismatch => write!(f, "units do not match the expected units"),
+            ParseQuantityError::OutOfRange => write!(f, "unit scale factor out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> std::error::Error for ParseQuantityError<E> {}
+
+/// A unit that may be referred to by name when parsing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Symbol {
+    /// The name of the unit, as it appears in strings.
+    pub symbol: &'static str,
+    /// The value of the unit in terms of the base units of its system.
+    pub value: f64,
+    /// The power of each base unit, in the order the base units are defined.
+    pub exponents: &'static [isize],
+}
+
+/// Find `name` in a symbol table, where earlier entries take precedence over later ones.
+///
+/// If `ignore_case` is true, `name` is instead matched ignoring ASCII case, but only against
+/// symbols without lowercase letters, such as the names of constants, so that e.g. `"MW"` is not
+/// taken for a symbol `"mW"`. When parsing, this is only tried after `name` has failed to match
+/// exactly, with or without an SI prefix, so that e.g. `"ns"` is a nanosecond, not `NS`.
+pub fn find_symbol<'a>(symbols: &'a [Symbol], name: &str, ignore_case: bool) -> Option<&'a Symbol> {
+    if ignore_case {
+        symbols.iter().find(|s| {
+            !s.symbol.bytes().any(|b| b.is_ascii_lowercase()) && s.symbol.eq_ignore_ascii_case(name)
+        })
+    } else {
+        symbols.iter().find(|s| s.symbol == name)
+    }
+}
+
+use crate::f64prefixes::*;
+
+/// The SI prefixes that can be applied to symbols when parsing
+const PREFIXES: [(&str, f64); 22] = [
+    ("da", DECA),
+    ("Y", YOTTA),
+    ("Z", ZETTA),
+    ("E", EXA),
+    ("P", PETA),
+    ("T", TERA),
+    ("G", GIGA),
+    ("M", MEGA),
+    ("k", KILO),
+    ("h", HECTO),
+    ("d", DECI),
+    ("c", CENTI),
+    ("m", MILLI),
+    ("µ", MICRO),
+    ("μ", MICRO),
+    ("u", MICRO),
+    ("n", NANO),
+    ("p", PICO),
+    ("f", FEMTO),
+    ("a", ATTO),
+    ("z", ZEPTO),
+    ("y", YOCTO),
+];
+
+/// Split a string into its value and units, as they are separated by the `Display` impls.
+#[doc(hidden)]
+pub fn split_quantity(s: &str) -> (&str, &str) {
+    match s.find(' ') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    }
+}
+
+/// Parse a units expression, adding the power of each base unit to `exponents` and returning the
+/// overall scale factor.
+///
+/// The function `lookup` gives the value and exponents of a unit symbol, matched exactly or, if
+/// its second argument is true, ignoring case. A symbol is first looked up exactly; then, if
+/// `prefixes` is true, with any SI prefix removed; then ignoring case; and finally ignoring case
+/// with any SI prefix removed.
+///
+/// The powers of each symbol are added up before its value is raised to them, so that e.g.
+/// `"km^3000/km^3000"` has a scale factor of 1 rather than overflowing.
+#[doc(hidden)]
+pub fn parse_expression<A, E>(
+    units: &str,
+    exponents: &mut [isize],
+    prefixes: bool,
+    lookup: &dyn Fn(&str, bool) -> Option<(f64, A)>,
+) -> Result<f64, ParseQuantityError<E>>
+where
+    A: AsRef<[isize]>,
+{
+    if units.is_empty() {
+        return Ok(1.0);
+    }
+    let parser = Parser { prefixes, lookup };
+
+    parser.symbols(units, 1, &mut |name, power| {
+        let (_, unit) = parser.symbol(name)?;
+        for (e, &x) in exponents.iter_mut().zip(unit.as_ref()) {
+            *e += power * x;
+        }
+        Ok(())
+    })?;
+
+    // Raise each distinct symbol to its total power, at its first occurrence.
+    let mut factor = 1.0;
+    let mut index = 0;
+    parser.symbols(units, 1, &mut |name, _| {
+        let (mut position, mut first, mut total) = (0, true, 0);
+        parser.symbols(units, 1, &mut |other, power| {
+            if other == name {
+                first &= position >= index;
+                total += power;
+            }
+            position += 1;
+            Ok(())
+        })?;
+        if first {
+            factor *= FloatCore::powi(parser.symbol(name)?.0, total as i32);
+        }
+        index += 1;
+        Ok(())
+    })?;
+    Ok(factor)
+}
+
+/// Convert a scale factor to the value type, failing if it can't be represented, e.g. if it is
+/// fractional and `V` is an integer. Rounding to the precision of an `f32` is allowed.
+#[doc(hidden)]
+pub fn convert_factor<V, E>(factor: f64) -> Result<V, ParseQuantityError<E>>
+where
+    V: num_traits::FromPrimitive + num_traits::ToPrimitive,
+{
+    let converted = V::from_f64(factor).ok_or(ParseQuantityError::OutOfRange)?;
+    let back = converted.to_f64().ok_or(ParseQuantityError::OutOfRange)?;
+    if FloatCore::abs(back - factor) <= FloatCore::abs(factor) * f64::from(f32::EPSILON) {
+        Ok(converted)
+    } else {
+        Err(ParseQuantityError::OutOfRange)
+    }
+}
+
+struct Parser<'a, A> {
+    prefixes: bool,
+    lookup: &'a dyn Fn(&str, bool) -> Option<(f64, A)>,
+}
+
+impl<'a, A: AsRef<[isize]>> Parser<'a, A> {
+    /// Call `f` with each symbol in `expr`, in order, and the power it is raised to when `expr` is
+    /// raised to `power`.
+    fn symbols<E>(
+        &self,
+        expr: &str,
+        power: isize,
+        f: &mut dyn FnMut(&str, isize) -> Result<(), ParseQuantityError<E>>,
+    ) -> Result<(), ParseQuantityError<E>> {
+        let mut rest = expr;
+        let mut sign = 1;
+
+        loop {
+            let (group, term, after) = if let Some(inner) = rest.strip_prefix('(') {
+                let close = matching_paren(inner).ok_or(ParseQuantityError::MalformedUnits)?;
+                (true, &inner[..close], &inner[close + 1..])
+            } else {
+                let end = rest.find(['*', '/', '^', '(', ')']).unwrap_or(rest.len());
+                (false, &rest[..end], &rest[end..])
+            };
+            if term.is_empty() {
+                return Err(ParseQuantityError::MalformedUnits);
+            }
+
+            let (exp, after) = match after.strip_prefix('^') {
+                Some(exp) => {
+                    let end = exp.find(['*', '/', '^', '(', ')']).unwrap_or(exp.len());
+                    let e = exp[..end]
+                        .parse::<isize>()
+                        .map_err(|_| ParseQuantityError::InvalidExponent)?;
+                    (e, &exp[end..])
+                }
+                None => (1, after),
+            };
+
+            let p = power * sign * exp;
+            if group {
+                self.symbols(term, p, f)?;
+            } else if term != "1" {
+                f(term, p)?;
+            }
+
+            match after.chars().next() {
+                None => return Ok(()),
+                Some('*') => sign = 1,
+                Some('/') => sign = -1,
+                Some(_) => return Err(ParseQuantityError::MalformedUnits),
+            }
+            rest = &after[1..];
+        }
+    }
+
+    /// Look up the unit `name`, giving its value, including any prefix, and its exponents.
+    fn symbol<E>(&self, name: &str) -> Result<(f64, A), ParseQuantityError<E>> {
+        let prefixed = |ignore_case| {
+            PREFIXES
+                .iter()
+                .filter(|_| self.prefixes)
+                .filter_map(|&(p, pvalue)| {
+                    let unit = name.strip_prefix(p).filter(|n| !n.is_empty())?;
+                    (self.lookup)(unit, ignore_case).map(|(value, unit)| (pvalue * value, unit))
+                })
+                .next()
+        };
+        (self.lookup)(name, false)
+            .or_else(|| prefixed(false))
+            .or_else(|| (self.lookup)(name, true))
+            .or_else(|| prefixed(true))
+            .ok_or(ParseQuantityError::UnknownUnit)
+    }
+}
+
+/// Find the index of the parenthesis that closes an expression that has already been opened.
+fn matching_paren(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Parse units of the form `tok1^exp1*tok2^exp2*...`, adding the exponent of each token to the
+/// corresponding entry of `exponents`.
+///
+/// Exponents are written as multiples of `1/denominator`, and are stored multiplied by
+/// `denominator`; a `denominator` of 2 allows exponents such as `1.5`.
+#[doc(hidden)]
+pub fn parse_units<E>(
+    units: &str,
+    tokens: &[&str],
+    exponents: &mut [isize],
+    denominator: isize,
+) -> Result<(), ParseQuantityError<E>> {
+    if units.is_empty() {
+        return Ok(());
+    }
+
+    for factor in units.split('*') {
+        let (token, exp) = match factor.find('^') {
+            Some(i) => (
+                &factor[..i],
+                parse_exponent(&factor[i + 1..], denominator)
+                    .ok_or(ParseQuantityError::InvalidExponent)?,
+            ),
+            None => (factor, denominator),
+        };
+
+        let index = tokens
+            .iter()
+            .position(|&t| t == token)
+            .ok_or(ParseQuantityError::UnknownUnit)?;
+        exponents[index] += exp;
+    }
+
+    Ok(())
+}
+
+fn parse_exponent(s: &str, denominator: isize) -> Option<isize> {
+    let (int, frac) = match s.find('.') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    };
+    let whole = if int == "-" || int == "+" {
+        0
+    } else {
+        int.parse::<isize>().ok()?
+    } * denominator;
+    let part = match (frac, denominator) {
+        ("", _) => 0,
+        ("5", 2) => 1,
+        _ => return None,
+    };
+
+    Some(if int.starts_with('-') {
+        whole - part
+    } else {
+        whole + part
+    })
+}
+
+#[test]
+fn test_parse_exponent() {
+    assert_eq!(parse_exponent("2", 1), Some(2));
+    assert_eq!(parse_exponent("-3", 1), Some(-3));
+    assert_eq!(parse_exponent("1.5", 1), None);
+    assert_eq!(parse_exponent("1.5", 2), Some(3));
+    assert_eq!(parse_exponent("-0.5", 2), Some(-1));
+    assert_eq!(parse_exponent("-1", 2), Some(-2));
+    assert_eq!(parse_exponent("x", 1), None);
+}
+
+#[test]
+fn test_parse_expression() {
+    const SYMBOLS: &[Symbol] = &[
+        Symbol {
+            symbol: "m",
+            value: 1.0,
+            exponents: &[1, 0],
+        },
+        Symbol {
+            symbol: "s",
+            value: 1.0,
+            exponents: &[0, 1],
+        },
+        Symbol {
+            symbol: "MIN",
+            value: 60.0,
+            exponents: &[0, 1],
+        },
+    ];
+    let lookup =
+        |s: &str, ignore_case| find_symbol(SYMBOLS, s, ignore_case).map(|s| (s.value, s.exponents));
+    let parse = |units: &str| {
+        let mut exponents = [0; 2];
+        parse_expression::<_, ()>(units, &mut exponents, true, &lookup).map(|f| (f, exponents))
+    };
+
+    assert_eq!(parse(""), Ok((1.0, [0, 0])));
+    assert_eq!(parse("m*s^-2"), Ok((1.0, [1, -2])));
+    assert_eq!(parse("m/s/s"), Ok((1.0, [1, -2])));
+    assert_eq!(parse("m/(s*s)"), Ok((1.0, [1, -2])));
+    assert_eq!(parse("1/(m*s)^2"), Ok((1.0, [-2, -2])));
+    assert_eq!(parse("km/min"), Ok((1000.0 / 60.0, [1, -1])));
+    assert_eq!(parse("min"), Ok((60.0, [0, 1])));
+    assert_eq!(parse("ms^2"), Ok((1e-6, [0, 2])));
+    assert_eq!(parse("μm"), Ok((1e-6, [1, 0])));
+    assert_eq!(parse("kmin"), Ok((60e3, [0, 1])));
+    assert_eq!(parse("(km*s)^3000/km^3000"), Ok((1.0, [0, 3000])));
+
+    assert_eq!(parse("ft"), Err(ParseQuantityError::UnknownUnit));
+    assert_eq!(parse("m^x"), Err(ParseQuantityError::InvalidExponent));
+    assert_eq!(parse("(m*s"), Err(ParseQuantityError::MalformedUnits));
+    assert_eq!(parse("m*"), Err(ParseQuantityError::MalformedUnits));
+    assert_eq!(parse("m)"), Err(ParseQuantityError::MalformedUnits));
+}
@@ -0,0 +1,94 @@
+//! Helpers for serializing quantities with serde
+//!
+//! With the `serde` feature, every unit system implements `Serialize` and `Deserialize` for its
+//! quantities as their bare values, so that a quantity has the same representation as its value
+//! type. The modules here provide other representations, for use with serde's `with` attribute.
+//!
+//! To serialize quantities as bare values in some unit other than the base units of their system,
+//! create a module for that unit with the `serde_in!` macro, which uses
+//! [`serialize_in`](fn.serialize_in.html) and [`deserialize_in`](fn.deserialize_in.html).
+
+use crate::Dimensioned;
+use core::ops::{Div, Mul};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize `quantity` as its value in terms of `unit`, a quantity of the same type.
+pub fn serialize_in<Q, S>(quantity: &Q, unit: &Q, serializer: S) -> Result<S::Ok, S::Error>
+where
+    Q: Dimensioned,
+    Q::Value: Clone + Div<Output = Q::Value> + Serialize,
+    S: Serializer,
+{
+    (quantity.value_unsafe().clone() / unit.value_unsafe().clone()).serialize(serializer)
+}
+
+/// Deserialize a quantity from its value in terms of `unit`, a quantity of the same type.
+pub fn deserialize_in<'de, Q, D>(unit: &Q, deserializer: D) -> Result<Q, D::Error>
+where
+    Q: Dimensioned,
+    Q::Value: Clone + Mul<Output = Q::Value> + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = Q::Value::deserialize(deserializer)?;
+    Ok(Q::new(value * unit.value_unsafe().clone()))
+}
+
+/// Serialize a quantity with its units, and check them when deserializing
+///
+/// A quantity is serialized as a string in the format of its `Display` implementation, such as
+/// `"3.5 m*s^-1"`, and deserialized with its `FromStr` implementation, which fails if the units
+/// don't match those of the type being deserialized. So, a length that was written to a file can't
+/// be read back as a time.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde(with = "dim::serde_helpers::tagged")]
+///     speed: si::MeterPerSecond<f64>,
+/// }
+/// ```
+pub mod tagged {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use core::str::FromStr;
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::Serializer;
+
+    /// Serialize `quantity` as a string, with its units.
+    pub fn serialize<Q, S>(quantity: &Q, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        Q: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(quantity)
+    }
+
+    /// Deserialize a quantity from a string, checking its units.
+    pub fn deserialize<'de, Q, D>(deserializer: D) -> Result<Q, D::Error>
+    where
+        Q: FromStr,
+        Q::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        struct QuantityVisitor<Q>(PhantomData<Q>);
+
+        impl<'de, Q> Visitor<'de> for QuantityVisitor<Q>
+        where
+            Q: FromStr,
+            Q::Err: fmt::Display,
+        {
+            type Value = Q;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a quantity with units, such as \"3.5 m*s^-1\"")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Q, E> {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(QuantityVisitor(PhantomData))
+    }
+}
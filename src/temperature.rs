@@ -0,0 +1,175 @@
+//! Absolute temperatures on the Kelvin, Celsius, Fahrenheit, and Rankine scales
+//!
+//! Converting between most units only takes multiplication, but the Celsius and Fahrenheit scales
+//! have offset zeros, so a reading of 20 °C is not 20 times anything. This module keeps such
+//! readings apart from temperature differences with the type `Absolute<K>`, a point on the
+//! thermodynamic scale wrapping a temperature quantity `K`, such as `si::Kelvin<f64>` or
+//! `ucum::Kelvin<f64>`.
+//!
+//! Differences are plain quantities of type `K`, and the arithmetic follows:
+//!
+//! * `Absolute<K> - Absolute<K>` is a difference, `K`.
+//! * `Absolute<K> + K`, `K + Absolute<K>`, and `Absolute<K> - K` are points, `Absolute<K>`.
+//! * Adding two points does not compile.
+//!
+//! A difference of one degree Celsius is one kelvin, and a difference of one degree Fahrenheit is
+//! one degree Rankine, or `ucum::DEGR`.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::si;
+//! use dim::temperature::Absolute;
+//!
+//! fn main() {
+//!     let freezing = Absolute::<si::Kelvin<f64>>::from_celsius(0.0);
+//!     let body = Absolute::<si::Kelvin<f64>>::from_fahrenheit(98.6);
+//!
+//!     assert_eq!(freezing.kelvin(), 273.15 * si::K);
+//!     assert!((body.celsius() - 37.0).abs() < 1e-12);
+//!
+//!     let rise = body - freezing;
+//!     assert!((rise / si::K - 37.0).abs() < 1e-12);
+//!
+//!     let warmer = freezing + 10.0 * si::K;
+//!     assert!((warmer.celsius() - 10.0).abs() < 1e-12);
+//! }
+//! ```
+
+use crate::dimensions::Temperature;
+use crate::{si, sia, ucum, Dimensioned};
+use core::fmt;
+use core::ops::{Add, Sub};
+use num_traits::float::FloatCore;
+
+/// A point on the thermodynamic temperature scale, stored as a temperature `K` above absolute zero.
+///
+/// See the [module documentation](index.html) for its arithmetic. `K` must be a temperature:
+///
+/// ```rust,compile_fail,E0277
+/// # extern crate dimensioned as dim;
+/// # use dim::si;
+/// # use dim::temperature::Absolute;
+/// # fn main() {
+/// let length = Absolute::new(3.0 * si::M);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Absolute<K> {
+    kelvin: K,
+}
+
+impl<K: Temperature> Absolute<K> {
+    /// Create a temperature that is `kelvin` above absolute zero.
+    pub const fn new(kelvin: K) -> Self {
+        Absolute { kelvin }
+    }
+
+    /// The temperature above absolute zero.
+    pub fn kelvin(&self) -> K
+    where
+        K: Clone,
+    {
+        self.kelvin.clone()
+    }
+}
+
+/// Convert an `f64` constant to the value type of a temperature.
+fn lit<V: FloatCore>(x: f64) -> V {
+    V::from(x).expect("temperature constants are representable by floating point types")
+}
+
+impl<K, V> Absolute<K>
+where
+    K: Temperature + Dimensioned<Value = V>,
+    V: FloatCore,
+{
+    /// Create a temperature from a reading in degrees Celsius.
+    pub fn from_celsius(celsius: V) -> Self {
+        Absolute::new(K::new(celsius + lit(273.15)))
+    }
+
+    /// Create a temperature from a reading in degrees Fahrenheit.
+    pub fn from_fahrenheit(fahrenheit: V) -> Self {
+        Absolute::new(K::new((fahrenheit + lit(459.67)) * lit(5.0 / 9.0)))
+    }
+
+    /// Create a temperature from a reading in degrees Rankine.
+    pub fn from_rankine(rankine: V) -> Self {
+        Absolute::new(K::new(rankine * lit(5.0 / 9.0)))
+    }
+
+    /// The reading of this temperature in degrees Celsius.
+    pub fn celsius(&self) -> V {
+        *self.kelvin.value_unsafe() - lit(273.15)
+    }
+
+    /// The reading of this temperature in degrees Fahrenheit.
+    pub fn fahrenheit(&self) -> V {
+        *self.kelvin.value_unsafe() * lit(9.0 / 5.0) - lit(459.67)
+    }
+
+    /// The reading of this temperature in degrees Rankine.
+    pub fn rankine(&self) -> V {
+        *self.kelvin.value_unsafe() * lit(9.0 / 5.0)
+    }
+}
+
+impl<K> Sub for Absolute<K>
+where
+    K: Temperature + Sub<Output = K>,
+{
+    type Output = K;
+    fn sub(self, rhs: Self) -> K {
+        self.kelvin - rhs.kelvin
+    }
+}
+
+impl<K> Add<K> for Absolute<K>
+where
+    K: Temperature + Add<Output = K>,
+{
+    type Output = Self;
+    fn add(self, rhs: K) -> Self {
+        Absolute::new(self.kelvin + rhs)
+    }
+}
+
+impl<K> Sub<K> for Absolute<K>
+where
+    K: Temperature + Sub<Output = K>,
+{
+    type Output = Self;
+    fn sub(self, rhs: K) -> Self {
+        Absolute::new(self.kelvin - rhs)
+    }
+}
+
+macro_rules! impl_add_point {
+    ($($Kelvin:ident)::+) => {
+        impl<V> Add<Absolute<$($Kelvin)::+<V>>> for $($Kelvin)::+<V>
+        where
+            V: Add<Output = V>,
+        {
+            type Output = Absolute<$($Kelvin)::+<V>>;
+            fn add(self, rhs: Absolute<$($Kelvin)::+<V>>) -> Self::Output {
+                Absolute::new(self + rhs.kelvin)
+            }
+        }
+    };
+}
+
+impl_add_point!(si::Kelvin);
+impl_add_point!(sia::Kelvin);
+impl_add_point!(ucum::Kelvin);
+
+/// Writes the temperature above absolute zero, e.g. `300 K`, just as `K` would be written.
+///
+/// A reading in kelvin is always absolute, but the output alone can't be told apart from a
+/// difference of the same size; use `celsius()` or `fahrenheit()` for readings on the offset scales.
+impl<K: fmt::Display> fmt::Display for Absolute<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.kelvin, f)
+    }
+}
@@ -6,6 +6,30 @@
 /// Allows one to refer to quantities generically.
 ///
 /// It is not recommened to implement this for anything outside this this crate.
+///
+/// ```rust,compile_fail,E0277
+/// extern crate dimensioned as dim;
+/// use dim::Dimensioned;
+///
+/// fn value<D: Dimensioned>(x: D) -> D::Value
+/// where
+///     D::Value: Copy,
+/// {
+///     *x.value_unsafe()
+/// }
+///
+/// fn main() {
+///     // error: `{float}` is not a quantity
+///     value(3.0);
+/// }
+/// ```
+#[cfg_attr(
+    diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` is not a quantity",
+        note = "quantities are the types of a unit system made with `make_units!`, such as `si::Meter<f64>`"
+    )
+)]
 pub trait Dimensioned {
     /// The type of the value of a quantity. E.g. For `si::Meter<f64>`, `Value` is `f64`.
     type Value;
@@ -24,6 +48,26 @@ pub trait Dimensioned {
 
 /// This trait is implemented for all quantities with no units. The unit systems that come with
 /// dimensioned use `Unitless<V>` for that type.
+///
+/// ```rust,compile_fail,E0277
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let mut x = 3.0 * si::M;
+///     // error: cannot multiply-assign `SI<f64, ...>` by `SI<f64, ...>`, as the right-hand side
+///     // isn't dimensionless
+///     x *= si::M;
+/// }
+/// ```
+#[cfg_attr(
+    diagnostic_namespace,
+    diagnostic::on_unimplemented(
+        message = "`{Self}` has units, but it needs to be dimensionless",
+        label = "this must have no units",
+        note = "dividing a quantity by a unit of the same type, as in `x / si::M`, leaves it dimensionless"
+    )
+)]
 pub trait Dimensionless: Dimensioned {
     /// Extract the value from a quantity with no units. As there are no units to ignore, it is
     /// dimensionally safe.
@@ -0,0 +1,169 @@
+extern crate dimensioned as dim;
+#[cfg(feature = "approx")]
+#[macro_use]
+extern crate approx;
+
+use dim::conversion::{InexactConversion, TryConvert};
+use dim::{cgs, fps, mks, si, sia, ucum};
+
+#[cfg(feature = "approx")]
+#[test]
+fn fps_to_si() {
+    assert_relative_eq!(si::Meter::from(fps::MI), si::MI);
+    assert_relative_eq!(
+        si::Kilogram::from(fps::SLUG),
+        14.593_902_937_206_364 * si::KG
+    );
+    assert_relative_eq!(si::Newton::from(fps::LBF), si::LBF);
+    assert_relative_eq!(si::Pascal::from(fps::PSI), si::PSI);
+    assert_relative_eq!(si::Joule::from(fps::BTU), 1_055.055_852_62 * si::J);
+    assert_relative_eq!(si::Watt::from(fps::HP), 745.699_871_582_270_2 * si::W);
+    assert_relative_eq!(si::MeterPerSecond2::from(fps::G0), 9.80665 * si::MPS2);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn si_to_fps() {
+    assert_relative_eq!(fps::Foot::from(si::FT), fps::FT);
+    assert_relative_eq!(fps::Pound::from(si::LB), fps::LB);
+    assert_relative_eq!(fps::Poundal::from(si::LBF), fps::LBF);
+    assert_relative_eq!(
+        fps::FootPerSecond::from(si::C0),
+        983_571_056.430_446_2 * fps::FTPS
+    );
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn fps_and_mks() {
+    assert_relative_eq!(mks::Meter::from(fps::FT), 0.3048 * mks::M);
+    assert_relative_eq!(mks::Kilogram::from(fps::LB), 0.45359237 * mks::KG);
+    assert_relative_eq!(fps::FootPerSecond::from(mks::MPS), fps::FTPS / 0.3048);
+
+    let x = 3.0 * fps::SQRTFT * fps::SQRTLB / fps::S;
+    assert_relative_eq!(fps::FPS::from(mks::MKS::from(x)), x);
+}
+
+#[test]
+fn value_types() {
+    let x: si::Meter<f32> = si::Meter::from(3.0_f32 * fps::f32consts::FT);
+    assert!((x.value_in(si::f32consts::M) - 0.9144).abs() < 1e-6);
+
+    let x: cgs::Centimeter<f32> = cgs::CGS::from(2.0_f32 * si::f32consts::M);
+    assert_eq!(x, 200.0 * cgs::f32consts::CM);
+}
+
+/// A dual number, `re + eps * ε` with `ε² = 0`, to check that conversions don't need floats.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Dual {
+    re: f64,
+    eps: f64,
+}
+
+impl std::ops::Mul for Dual {
+    type Output = Dual;
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual {
+            re: self.re * rhs.re,
+            eps: self.re * rhs.eps + self.eps * rhs.re,
+        }
+    }
+}
+
+impl std::ops::Div for Dual {
+    type Output = Dual;
+    fn div(self, rhs: Dual) -> Dual {
+        Dual {
+            re: self.re / rhs.re,
+            eps: (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        }
+    }
+}
+
+impl dim::num_traits::ToPrimitive for Dual {
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.re)
+    }
+}
+
+impl dim::num_traits::NumCast for Dual {
+    fn from<T: dim::num_traits::ToPrimitive>(n: T) -> Option<Dual> {
+        n.to_f64().map(|re| Dual { re, eps: 0.0 })
+    }
+}
+
+#[test]
+fn custom_value_types() {
+    let x = si::Meter::new(Dual { re: 3.0, eps: 1.0 });
+    assert_eq!(
+        cgs::Centimeter::from(x),
+        cgs::Centimeter::new(Dual {
+            re: 300.0,
+            eps: 100.0
+        })
+    );
+    assert_eq!(
+        si::Kilogram::from(ucum::Gram::new(Dual {
+            re: 1500.0,
+            eps: 2.0
+        })),
+        si::Kilogram::new(Dual {
+            re: 1.5,
+            eps: 0.002
+        })
+    );
+}
+
+#[test]
+fn integer_exact() {
+    let x = si::Kilogram::new(3);
+    assert_eq!(x.try_convert(), Ok(ucum::Gram::new(3000)));
+    assert_eq!(ucum::Gram::new(3000).try_convert(), Ok(x));
+
+    let x = mks::Meter::new(2);
+    assert_eq!(x.try_convert(), Ok(cgs::Centimeter::new(200)));
+    assert_eq!(cgs::Centimeter::new(200).try_convert(), Ok(x));
+
+    // Conversions that don't scale the value are also `From` for integers
+    let x = si::Meter::new(7_i64);
+    assert_eq!(mks::Meter::from(x), mks::Meter::new(7));
+    assert_eq!(x.try_convert(), Ok(mks::Meter::new(7)));
+}
+
+#[test]
+fn integer_inexact() {
+    let foot: Result<fps::Foot<i32>, _> = si::Meter::new(1).try_convert();
+    assert_eq!(foot, Err(InexactConversion));
+
+    let kilogram: Result<si::Kilogram<i32>, _> = ucum::Gram::new(1500).try_convert();
+    assert_eq!(kilogram, Err(InexactConversion));
+
+    let gram: Result<ucum::Gram<i16>, _> = si::Kilogram::new(100).try_convert();
+    assert_eq!(gram, Err(InexactConversion));
+}
+
+#[test]
+#[should_panic(expected = "can't be represented")]
+fn integer_inexact_from() {
+    let _ = fps::Foot::from(si::Meter::new(1));
+}
+
+#[test]
+fn angles() {
+    let w = 3.0 * sia::RADPS;
+    let u = ucum::UCUM::from(w);
+    assert_eq!(u, 3.0 * ucum::RAD / ucum::S);
+    assert_eq!(sia::RadianPerSecond::from(u), w);
+
+    let k = 2.0 * sia::NMPRAD;
+    assert_eq!(sia::NewtonMeterPerRadian::from(ucum::UCUM::from(k)), k);
+
+    assert_eq!(si::Hertz::from(w), 3.0 * si::HZ);
+    assert_eq!(sia::SIA::from(2.0 * si::N), 2.0 * sia::N);
+}
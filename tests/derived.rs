@@ -1,3 +1,6 @@
+#![cfg_attr(feature = "oibit", feature(negative_impls))]
+
+#[macro_use]
 extern crate dimensioned as dim;
 
 use crate::dim::si::{Meter, Second};
@@ -18,3 +21,62 @@ fn derived() {
 
     assert_eq!(d / t, v);
 }
+
+mod mks {
+    make_units! {
+        MKS;
+        ONE: Unitless;
+
+        base {
+            M: Meter, "m", Length;
+            KG: Kilogram, "kg", Mass;
+            S: Second, "s", Time;
+        }
+
+        derived {
+            J: Joule, "J" = (Kilogram * MeterPerSecond^2), Energy;
+            KJ: Kilojoule, "kJ" = (Joule) * 1000;
+            MPS: MeterPerSecond = (Meter / Second), Velocity;
+            MPS2: MeterPerSecond2 = (Meter / Second^2), Acceleration;
+            M3: Meter3 = (Meter^3), Volume;
+            PS2: PerSecond2 = (Second^-2);
+            KGPM3: KilogramPerMeter3 = (Kilogram / (Meter * Meter^2));
+            W: Watt = ((Kilogram * Meter^2) / Second^3 / (Meter^0)), Power;
+        }
+
+        constants {}
+
+        fmt = true;
+    }
+    pub use self::f64consts::*;
+}
+
+#[test]
+fn derived_expressions() {
+    use self::mks::*;
+
+    let j: Joule<f64> = 2.0 * KG * M * M / S / S;
+    assert_eq!(j, 2.0 * J);
+
+    let a: MeterPerSecond2<f64> = 3.0 * MPS / S;
+    assert_eq!(a, 3.0 * M * PS2);
+
+    let v: Meter3<f64> = M * M * M;
+    assert_eq!(v * KGPM3, 1.0 * KG);
+
+    let p: Watt<f64> = J / S;
+    assert_eq!(p, 1.0 * W);
+}
+
+#[test]
+fn scaled_derived_units() {
+    use self::mks::*;
+
+    let e: Kilojoule<f64> = 2.0 * KJ;
+    assert_eq!(e, 2000.0 * J);
+    assert_eq!(mks::f32consts::KJ, 1000.0 * mks::f32consts::J);
+
+    // values are in base units, so they aren't printed with the symbol of a scaled unit
+    assert_eq!(format!("{}", e), "2000 J");
+    assert_eq!(format!("{}", e.display_in(KJ, "kJ")), "2 kJ");
+}
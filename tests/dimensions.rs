@@ -0,0 +1,80 @@
+extern crate dimensioned as dim;
+
+use crate::dim::dimensions::*;
+use crate::dim::typenum::{Prod, Quot};
+use crate::dim::{cgs, fps, mks, si, sia, ucum};
+use std::ops::{Div, Mul};
+
+fn density<M, V>(mass: M, volume: V) -> Quot<M, V>
+where
+    M: Mass + Div<V>,
+    V: Volume,
+    Quot<M, V>: Density,
+{
+    mass / volume
+}
+
+fn momentum<M, V>(mass: M, velocity: V) -> Prod<M, V>
+where
+    M: Mass + Mul<V>,
+    V: Velocity,
+    Prod<M, V>: Momentum,
+{
+    mass * velocity
+}
+
+#[test]
+fn generic_over_systems() {
+    assert_eq!(density(2.0 * si::KG, si::M3), 2.0 * si::KGPM3);
+    assert_eq!(density(2.0 * cgs::G, cgs::CM3), 2.0 * cgs::GPCM3);
+    assert_eq!(density(2.0 * fps::LB, fps::FT3), 2.0 * fps::LBPFT3);
+    assert_eq!(density(2.0 * mks::KG, mks::M3), 2.0 * mks::KGPM3);
+    assert_eq!(density(2.0 * ucum::G, ucum::M3), 2.0 * ucum::GPM3);
+
+    assert_eq!(momentum(2.0 * si::KG, si::MPS), 2.0 * si::NS);
+    assert_eq!(momentum(2.0 * cgs::G, cgs::CMPS), 2.0 * cgs::DYNS);
+    assert_eq!(momentum(2.0 * fps::LB, fps::FTPS), 2.0 * fps::PDLS);
+    assert_eq!(momentum(2.0 * mks::KG, mks::MPS), 2.0 * mks::KGMPS);
+    assert_eq!(momentum(2.0 * ucum::G, ucum::MPS), 2.0 * ucum::MILLINS);
+}
+
+#[test]
+fn named_units() {
+    // These only need to compile.
+    fn torque<T: Torque>(_: T) {}
+    fn angular_velocity<T: AngularVelocity>(_: T) {}
+    fn flux_density<T: MagneticFluxDensity>(_: T) {}
+    fn illuminance<T: Illuminance>(_: T) {}
+    fn luminous_flux<T: LuminousFlux>(_: T) {}
+    fn absorbed_dose<T: AbsorbedDose>(_: T) {}
+    fn catalytic_activity<T: CatalyticActivity>(_: T) {}
+    fn dynamic_viscosity<T: DynamicViscosity>(_: T) {}
+    fn heat_capacity<T: HeatCapacity>(_: T) {}
+    fn thermal_conductivity<T: ThermalConductivity>(_: T) {}
+
+    torque(si::N * si::M);
+    torque(sia::N * sia::M / sia::RAD);
+    torque(fps::PDL * fps::FT);
+    torque(cgs::DYN * cgs::CM);
+    torque(ucum::MILLIN * ucum::M / ucum::RAD);
+
+    angular_velocity(si::HZ);
+    angular_velocity(sia::RAD / sia::S);
+    angular_velocity(ucum::RAD / ucum::S);
+
+    flux_density(si::T);
+    flux_density(ucum::T);
+    illuminance(si::LX);
+    illuminance(sia::LX);
+    luminous_flux(si::LM);
+    luminous_flux(sia::LM);
+    absorbed_dose(si::GY);
+    absorbed_dose(ucum::GY);
+    catalytic_activity(si::KAT);
+    dynamic_viscosity(si::PAS);
+    dynamic_viscosity(cgs::P);
+    dynamic_viscosity(ucum::MILLIPS);
+    heat_capacity(si::J / si::K);
+    thermal_conductivity(si::W / si::M / si::K);
+    thermal_conductivity(ucum::MILLIWPMK);
+}
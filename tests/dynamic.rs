@@ -0,0 +1,69 @@
+extern crate dimensioned as dim;
+
+use crate::dim::dynamic::DimensionMismatch;
+use crate::dim::si::{self, Dynamic};
+use crate::dim::{cgs, ucum};
+use std::convert::TryFrom;
+
+#[test]
+fn conversions() {
+    let d = Dynamic::from(3.0 * si::N);
+    assert_eq!(d.value(), &3.0);
+    assert_eq!(d.exponents(), &[1, 1, -2, 0, 0, 0, 0]);
+    assert!(!d.is_dimensionless());
+
+    assert_eq!(si::Newton::try_from(d), Ok(3.0 * si::N));
+    assert_eq!(si::Joule::try_from(d), Err(DimensionMismatch));
+
+    let n: ucum::MilliNewton<i32> = ucum::Dynamic::new(4, [1, -2, 1, 0, 0, 0, 0])
+        .try_into()
+        .unwrap();
+    assert_eq!(n, 4 * ucum::i32consts::MILLIN);
+
+    let q = cgs::Dynamic::from(2.0 * cgs::STATC);
+    assert_eq!(q.exponents(), &[3, 1, -1]);
+
+    assert!(Dynamic::from(2.0 * si::ONE).is_dimensionless());
+}
+
+#[test]
+fn arithmetic() {
+    let m = Dynamic::from(6.0 * si::M);
+    let s = Dynamic::from(2.0 * si::S);
+
+    assert_eq!(si::MeterPerSecond::try_from(m / s), Ok(3.0 * si::MPS));
+    assert_eq!(si::Meter2::try_from(m * m), Ok(36.0 * si::M2));
+    assert_eq!(si::Meter::try_from(m + m), Ok(12.0 * si::M));
+    assert_eq!(si::Meter::try_from(m - m), Ok(0.0 * si::M));
+    assert_eq!(si::Meter3::try_from(m.powi(3)), Ok(216.0 * si::M3));
+    assert_eq!(si::PerSecond2::try_from(s.powi(-2)), Ok(0.25 * si::PS2));
+    assert_eq!(si::Unitless::try_from(s.powi(0)), Ok(1.0 * si::ONE));
+
+    assert_eq!(m.checked_add(m), Ok(Dynamic::from(12.0 * si::M)));
+    assert_eq!(m.checked_add(s), Err(DimensionMismatch));
+    assert_eq!(m.checked_sub(s), Err(DimensionMismatch));
+    assert_eq!(m.checked_powi(2), Some(Dynamic::from(36.0 * si::M2)));
+    assert_eq!(
+        Dynamic::new(1.0, [isize::MAX, 0, 0, 0, 0, 0, 0]).checked_powi(2),
+        None
+    );
+
+    // the same as with static units
+    let (m, s) = (6.0 * si::M, 2.0 * si::S);
+    assert_eq!(
+        si::Joule::try_from(Dynamic::from(si::KG) * Dynamic::from(m / s).powi(2)),
+        Ok(si::KG * (m / s) * (m / s))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempted to raise units to a power that overflows")]
+fn powi_overflow() {
+    let _ = Dynamic::new(1.0, [isize::MAX, 0, 0, 0, 0, 0, 0]).powi(2);
+}
+
+#[test]
+#[should_panic(expected = "attempted to add quantities with different units")]
+fn add_mismatched() {
+    let _ = Dynamic::from(6.0 * si::M) + Dynamic::from(2.0 * si::S);
+}
@@ -2,7 +2,9 @@
 
 extern crate dimensioned as dim;
 
+use crate::dim::fmt::{BaseUnits, Engineering, Style, Styled, WriteUnits};
 use crate::dim::si::{self, f64consts::*};
+use crate::dim::{cgs, ucum};
 
 #[test]
 fn formatting() {
@@ -12,6 +14,233 @@ fn formatting() {
     assert_eq!(<si::Second<f32>>::to_string(), "s");
     assert_eq!(format!("{}", 3.5 * S), "3.5 s");
 
-    assert_eq!(<si::Newton<f32>>::to_string(), "m*kg*s^-2");
-    assert_eq!(format!("{}", 3.5 * N), "3.5 m*kg*s^-2");
+    assert_eq!(<si::Newton<f32>>::to_string(), "N");
+    assert_eq!(format!("{}", 3.5 * N), "3.5 N");
+    assert_eq!(format!("{:?}", 3.5 * N), "3.5 m*kg*s^-2");
+}
+
+#[test]
+fn derived_symbols() {
+    assert_eq!(format!("{}", 2.0 * J), "2 J");
+    assert_eq!(format!("{}", 2.0 * W), "2 W");
+    assert_eq!(format!("{}", 2.0 * PA), "2 Pa");
+    assert_eq!(format!("{}", 2.0 * V), "2 V");
+    assert_eq!(format!("{}", 2.0 * OHM), "2 Ω");
+    assert_eq!(format!("{}", 2.0 * HZ), "2 Hz");
+    // aliases have no symbol of their own, and print as the unit they are the same type as
+    assert_eq!(format!("{}", 2.0 * BQ), "2 Hz");
+    assert_eq!(format!("{}", 2.0 * SV), "2 Gy");
+    assert_eq!(format!("{}", 2.0 * LX), "2 m^-2*cd");
+    assert_eq!(format!("{}", 2.0 * N * M), "2 J");
+    // base units take precedence
+    assert_eq!(format!("{}", 2.0 * LM), "2 cd");
+    // units without a symbol are still written in base units
+    assert_eq!(format!("{}", 2.0 * MPS), "2 m*s^-1");
+
+    assert_eq!(format!("{}", 2.0 * ucum::MILLIN), "2 mN");
+    assert_eq!(format!("{}", 2.0 * ucum::A), "2 A");
+    assert_eq!(format!("{}", 2.0 * ucum::MILLIOHM), "2 mΩ");
+    // scaled units print with the symbol of the unit of value 1 they share a type with, as the
+    // value is never rescaled; `Engineering` picks the prefix instead
+    assert_eq!(format!("{}", 2.0 * ucum::N), "2000 mN");
+    assert_eq!(format!("{}", 2.0 * ucum::F), "0.002 kF");
+}
+
+#[test]
+fn base_units() {
+    assert_eq!(format!("{}", BaseUnits(&(3.5 * N))), "3.5 m*kg*s^-2");
+    assert_eq!(
+        format!("{:.2}", BaseUnits(&(3.5 * OHM))),
+        "3.50 m^2*kg*s^-3*A^-2"
+    );
+    assert_eq!(format!("{:e}", BaseUnits(&(3500.0 * M))), "3.5e3 m");
+    assert_eq!(format!("{}", BaseUnits(&(3.5 * ONE))), "3.5");
+    assert_eq!(
+        format!("{}", BaseUnits(&(3.0 * cgs::STATC))),
+        "3 cm^1.5*g^0.5*s^-1"
+    );
+}
+
+#[test]
+fn engineering() {
+    assert_eq!(format!("{}", Engineering(&(0.0032 * M))), "3.2 mm");
+    assert_eq!(format!("{}", Engineering(&(4.7e-9 * F))), "4.7 nF");
+    assert_eq!(format!("{}", Engineering(&(2.2e6 * OHM))), "2.2 MΩ");
+    assert_eq!(format!("{}", Engineering(&(1.5e5 * PA))), "150 kPa");
+    assert_eq!(format!("{}", Engineering(&(0.002 * MOL))), "2 mmol");
+    assert_eq!(format!("{}", Engineering(&(-500.0 * M))), "-500 m");
+    assert_eq!(format!("{}", Engineering(&(0.0 * M))), "0 m");
+    assert_eq!(format!("{:.1}", Engineering(&(3.0e-30 * M))), "0.0 ym");
+    assert_eq!(format!("{}", Engineering(&(1e-3 * M))), "1 mm");
+    assert_eq!(format!("{}", Engineering(&(1e-6 * M))), "1 µm");
+    assert_eq!(format!("{}", Engineering(&(1e3 * M))), "1 km");
+    assert_eq!(
+        format!("{:.1}", Engineering(&si::Meter::new(0.0032f32))),
+        "3.2 mm"
+    );
+
+    // prefixed symbols are rescaled
+    assert_eq!(format!("{}", Engineering(&(0.5 * KG))), "500 g");
+    assert_eq!(
+        format!("{}", Engineering(&(3200.0 * ucum::MILLIN))),
+        "3.2 N"
+    );
+    assert_eq!(format!("{}", Engineering(&(2.0 * ucum::N))), "2 N");
+    assert_eq!(format!("{}", Engineering(&(2.0 * ucum::F))), "2 F");
+
+    // anything else is formatted as usual
+    assert_eq!(format!("{}", Engineering(&(3.5 * ONE))), "3.5");
+    assert_eq!(format!("{}", Engineering(&(0.0032 * MPS))), "0.0032 m*s^-1");
+    assert_eq!(format!("{}", Engineering(&(f64::NAN * M))), "NaN m");
+}
+
+#[test]
+fn styles() {
+    use crate::dim::typenum::{Pow, P12};
+
+    let f = 3.5 * N;
+    let styled = |style| format!("{}", Styled::new(&f, style).base_units());
+    assert_eq!(styled(Style::Ascii), "3.5 m*kg*s^-2");
+    assert_eq!(styled(Style::Unicode), "3.5 m·kg·s⁻²");
+    assert_eq!(styled(Style::Latex), r"3.5\,\mathrm{m\,kg\,s^{-2}}");
+    assert_eq!(
+        styled(Style::Siunitx),
+        r"\SI{3.5}{\metre\kilo\gram\per\second\squared}"
+    );
+
+    // derived symbols
+    let r = 2.0 * ucum::MILLIOHM;
+    assert_eq!(format!("{}", Styled::new(&r, Style::Unicode)), "2 mΩ");
+    assert_eq!(
+        format!("{}", Styled::new(&r, Style::Latex)),
+        r"2\,\mathrm{m\Omega{}}"
+    );
+    assert_eq!(
+        format!("{}", Styled::new(&r, Style::Siunitx)),
+        r"\SI{2}{\milli\ohm}"
+    );
+
+    // the value's format spec still applies
+    let v = 1234.5 * MPS3;
+    assert_eq!(
+        format!("{:e}", Styled::new(&v, Style::Unicode)),
+        "1.2345e3 m·s⁻³"
+    );
+    assert_eq!(
+        format!("{:.0}", Styled::new(&v, Style::Siunitx)),
+        r"\SI{1234}{\metre\per\second\cubed}"
+    );
+    let x = 2.0 * M2 * S3 / A.powi(P12::new());
+    assert_eq!(
+        format!("{}", Styled::new(&x, Style::Unicode)),
+        "2 m²·s³·A⁻¹²"
+    );
+
+    // unitless quantities
+    assert_eq!(
+        format!("{}", Styled::new(&(3.5 * ONE), Style::Latex)),
+        "3.5"
+    );
+    assert_eq!(
+        format!("{}", Styled::new(&(3.5 * ONE), Style::Siunitx)),
+        r"\num{3.5}"
+    );
+
+    // symbols siunitx has no macro for are written as they are
+    let h = 2.0 * HR;
+    assert_eq!(
+        format!("{}", Styled::new(&h, Style::Siunitx)),
+        r"\SI{7200}{\second}"
+    );
+
+    // half-integer exponents in CGS
+    let q = 3.0 * cgs::STATC;
+    let styled = |style| format!("{}", Styled::new(&q, style));
+    assert_eq!(styled(Style::Ascii), "3 cm^1.5*g^0.5*s^-1");
+    assert_eq!(styled(Style::Unicode), "3 cm³ᐟ²·g¹ᐟ²·s⁻¹");
+    assert_eq!(
+        styled(Style::Latex),
+        r"3\,\mathrm{cm^{3/2}\,g^{1/2}\,s^{-1}}"
+    );
+    assert_eq!(
+        styled(Style::Siunitx),
+        r"\SI{3}{\centi\metre\tothe{1.5}\gram\tothe{0.5}\per\second}"
+    );
+}
+
+#[test]
+fn display_in() {
+    use crate::dim::f64prefixes::*;
+
+    let d = 1500.0 * M;
+    assert_eq!(format!("{}", d.display_in(KILO * M, "km")), "1.5 km");
+    assert_eq!(format!("{:.3e}", d.display_in(FT, "ft")), "4.921e3 ft");
+    assert_eq!(format!("{}", d.display_in(M, "")), "1500");
+
+    let v = 10.0 * MPS;
+    assert_eq!(
+        format!("{}", v.display_in(KILO * M / HR, "km/h")),
+        "36 km/h"
+    );
+
+    let d = si::Meter::new(5000);
+    assert_eq!(
+        format!("{}", d.display_in(si::Meter::new(1000), "km")),
+        "5 km"
+    );
+
+    let t = 3.0 * cgs::S;
+    let ms = MILLI * cgs::S;
+    assert_eq!(format!("{}", t.display_in(ms, "ms")), "3000 ms");
+}
+
+#[test]
+fn write_units() {
+    use std::fmt::{self, Write};
+
+    /// A fixed-size buffer, as might be used without an allocator
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    fn units<T: WriteUnits>() -> String {
+        let mut buffer = Buffer {
+            bytes: [0; 32],
+            len: 0,
+        };
+        T::write_units(&mut buffer).unwrap();
+        std::str::from_utf8(&buffer.bytes[..buffer.len])
+            .unwrap()
+            .to_owned()
+    }
+
+    assert_eq!(units::<si::Unitless<f64>>(), "");
+    assert_eq!(units::<si::Meter<f64>>(), "m");
+    assert_eq!(units::<si::Ohm<f64>>(), "Ω");
+    assert_eq!(units::<si::JoulePerKilogramKelvin<f64>>(), "m^2*s^-2*K^-1");
+    assert_eq!(units::<cgs::StatCoulomb<f64>>(), "cm^1.5*g^0.5*s^-1");
+
+    const EXPONENTS: &[isize] = <si::Volt<f32> as WriteUnits>::EXPONENTS;
+    assert_eq!(EXPONENTS, &[2, 1, -3, -1, 0, 0, 0]);
+    assert_eq!(
+        <ucum::MilliNewton<f32> as WriteUnits>::EXPONENTS,
+        &[1, -2, 1, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        <cgs::StatCoulomb<f32> as WriteUnits>::EXPONENTS,
+        &[3, 1, -1]
+    );
 }
@@ -37,3 +37,17 @@ fn derived_int_consts() {
     assert_eq!(Newton::new(1), si::u64consts::N);
     assert_eq!(Newton::new(1), si::usize_consts::N);
 }
+
+#[test]
+fn integer_constants() {
+    use crate::si::{Meter, Second};
+
+    assert_eq!(Second::new(60), si::u32consts::MIN);
+    assert_eq!(Second::new(3600), si::i32consts::HR);
+    assert_eq!(Second::new(86_400), si::u64consts::DAY);
+    assert_eq!(Meter::new(1000), si::i64consts::KM);
+    assert_eq!(Meter::new(149_597_870_700), si::u64consts::AU);
+
+    let timestamp = 2 * si::u32consts::DAY + 3 * si::u32consts::HR;
+    assert_eq!(timestamp, Second::new(183_600));
+}
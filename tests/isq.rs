@@ -0,0 +1,36 @@
+extern crate dimensioned as dim;
+
+use crate::dim::isq::{self, IsqDimension};
+use crate::dim::{cgs, fps, mks, si, sia, ucum};
+
+fn dimension<Q: IsqDimension>(_: Q) -> [isize; 7] {
+    Q::DIMENSION
+}
+
+fn energy<Q: IsqDimension<Dimension = isq::Energy>>(_: Q) {}
+
+#[test]
+fn same_dimension_in_every_system() {
+    energy(si::J);
+    energy(sia::J);
+    energy(ucum::J);
+    energy(cgs::ERG);
+    energy(fps::FTPDL);
+    energy(mks::KG * mks::M * mks::M / mks::S / mks::S);
+}
+
+#[test]
+fn exponents() {
+    assert_eq!(dimension(si::ONE), [0; 7]);
+    assert_eq!(dimension(si::MOL), [0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(dimension(si::CD), [0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(dimension(si::KAT), [0, 0, -1, 0, 0, 1, 0]);
+    assert_eq!(dimension(sia::RAD / sia::S), [0, 0, -1, 0, 0, 0, 0]);
+    assert_eq!(dimension(sia::MOL), [0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(dimension(ucum::C), [0, 0, 1, 1, 0, 0, 0]);
+    assert_eq!(dimension(ucum::A), [0, 0, 0, 1, 0, 0, 0]);
+    assert_eq!(dimension(ucum::V), dimension(si::V));
+    assert_eq!(dimension(cgs::CM), [1, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(dimension(cgs::P), dimension(si::PAS));
+    assert_eq!(dimension(fps::PDLPFT2), dimension(si::PA));
+}
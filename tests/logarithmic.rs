@@ -0,0 +1,63 @@
+#![cfg(any(feature = "std", feature = "nightly"))]
+
+extern crate dimensioned as dim;
+
+use dim::logarithmic::{self, Level, Reference, DBM, DBV, DBW, SPL};
+use dim::{si, ucum};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn conversions() {
+    let l = Level::from_quantity(1.0 * si::W, DBM);
+    assert!(close(l.decibels(), 30.0));
+    assert!(close(l.bels(), 3.0));
+    assert!(close(*(l.quantity() / si::W), 1.0));
+    assert!(close(l.relative_to(DBW).decibels(), 0.0));
+
+    let l = Level::from_quantity(10.0 * si::V, DBV);
+    assert!(close(l.decibels(), 20.0));
+    assert!(close(l.nepers(), 10.0_f64.ln()));
+    assert!(close(Level::from_nepers(l.nepers(), DBV).decibels(), 20.0));
+    assert!(close(Level::from_bels(2.0, DBV).decibels(), 20.0));
+
+    let l = Level::from_quantity(0.2 * si::PA, SPL);
+    assert!(close(l.decibels(), 80.0));
+
+    let reference = Reference::root_power(ucum::UCUM::from(20e-6 * si::PA), "20 µPa");
+    let l = Level::from_quantity(ucum::UCUM::from(0.2 * si::PA), reference);
+    assert!(close(l.decibels(), 80.0));
+    assert!(close(*(si::Pascal::from(l.quantity()) / si::PA), 0.2));
+}
+
+#[test]
+fn addition() {
+    let l = Level::new(90.0, SPL);
+    assert!(close((l + l).decibels(), 90.0 + 10.0 * 2.0_f64.log10()));
+
+    let a = Level::new(30.0, DBM);
+    let b = Level::new(0.0, DBW);
+    let sum = a + b;
+    assert_eq!(sum.reference(), DBM);
+    assert!(close(*(sum.quantity() / si::W), 2.0));
+
+    assert!(close(a.amplify(-3.0).decibels(), 27.0));
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Level::new(30.0, DBM)), "30 dB re 1 mW");
+    assert_eq!(
+        format!("{:.2}", Level::new(94.0, SPL)),
+        "94.00 dB re 20 µPa"
+    );
+}
+
+#[test]
+fn ph() {
+    let water = logarithmic::from_ph(7.0);
+    assert!(close(*(water / si::MOLPM3), 1e-4));
+    assert!(close(logarithmic::ph(water), 7.0));
+}
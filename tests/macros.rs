@@ -0,0 +1,85 @@
+#![cfg(feature = "macros")]
+#![cfg_attr(feature = "oibit", feature(negative_impls))]
+
+#[macro_use]
+extern crate dimensioned as dim;
+
+use crate::dim::fmt::WriteUnits;
+
+macro_rules! define_mks {
+    ($make_units:path) => {
+        $make_units! {
+            MKS;
+            ONE: Unitless;
+
+            base {
+                M: Meter, "m", Length;
+                KG: Kilogram, "kg", Mass;
+                S: Second, "s", Time;
+            }
+
+            derived {
+                N: Newton, "N" = (Kilogram * Meter / Second^2), Force;
+                J: Joule, "J" = (Newton * Meter), Energy;
+                KJ: Kilojoule = (Joule) * 1000.0;
+                PA: Pascal, "Pa" = (Newton / Meter2), Pressure;
+                M2: Meter2 = (Meter^2), Area;
+            }
+
+            constants {
+                MIN: Second = 60.0 * S.value_unsafe, integer;
+                AU: Meter = 149_597_870_700.0 * M.value_unsafe, integer(i64, u64);
+                DOWN: Meter = -2.0 * M.value_unsafe, integer(i8, isize);
+                FT: Meter = 0.3048;
+            }
+
+            fmt = true;
+        }
+    };
+}
+
+mod declarative {
+    define_mks!(make_units);
+    pub use self::f64consts::*;
+}
+
+mod procedural {
+    define_mks!(dim::macros::make_units);
+    pub use self::f64consts::*;
+}
+
+#[test]
+fn same_units() {
+    assert_eq!(
+        <procedural::Newton<f64> as WriteUnits>::EXPONENTS,
+        <declarative::Newton<f64> as WriteUnits>::EXPONENTS
+    );
+    assert_eq!(
+        <procedural::Pascal<f64> as WriteUnits>::EXPONENTS,
+        <declarative::Pascal<f64> as WriteUnits>::EXPONENTS
+    );
+    assert_eq!(
+        <procedural::Kilojoule<f64> as WriteUnits>::EXPONENTS,
+        <declarative::Kilojoule<f64> as WriteUnits>::EXPONENTS
+    );
+}
+
+#[test]
+fn same_api() {
+    use self::procedural::*;
+
+    let f: Newton<f64> = 2.0 * KG * M / S / S;
+    assert_eq!(f * M, 2.0 * J);
+    assert_eq!(KJ, 1000.0 * J);
+    assert_eq!(format!("{}", f), "2 N");
+    assert_eq!("2 N".parse::<Newton<f64>>().unwrap(), f);
+    assert_eq!(FT.value_unsafe, declarative::FT.value_unsafe);
+    assert_eq!(u32consts::MIN, Second::new(60));
+    assert_eq!(i8consts::N, Newton::new(1));
+    assert_eq!(
+        i64consts::AU.value_unsafe,
+        declarative::i64consts::AU.value_unsafe
+    );
+    assert_eq!(u64consts::AU, Meter::new(149_597_870_700));
+    assert_eq!(isize_consts::DOWN, Meter::new(-2));
+}
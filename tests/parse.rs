@@ -0,0 +1,162 @@
+#![cfg(feature = "std")]
+#![cfg_attr(feature = "oibit", feature(negative_impls))]
+
+#[macro_use]
+extern crate dimensioned as dim;
+
+use crate::dim::parse::ParseQuantityError;
+use crate::dim::{cgs, si, ucum};
+
+mod ms {
+    make_units! {
+        MS;
+        ONE: Unitless;
+
+        base {
+            M: Meter, "m", Length;
+            S: Second, "s", Time;
+        }
+
+        derived {
+            MPS: MeterPerSecond = (Meter / Second), Velocity;
+            HZ: Hertz, "Hz" = (Unitless / Second), Frequency;
+        }
+
+        constants {}
+
+        fmt = true;
+    }
+    pub use self::f64consts::*;
+}
+
+#[test]
+fn round_trip() {
+    fn check<T>(x: T)
+    where
+        T: std::fmt::Display + std::str::FromStr + PartialEq + std::fmt::Debug,
+        T::Err: std::fmt::Debug,
+    {
+        assert_eq!(x, x.to_string().parse().unwrap());
+    }
+
+    check(3.5 * si::ONE);
+    check(3.5 * si::S);
+    check(3.5 * si::N);
+    check(-2.0e-7 * si::WB);
+    check(3.5 * ucum::MILLIN);
+    check(7 * si::i32consts::J);
+    check(3.5 * ms::MPS);
+    check(3.5 * ms::HZ);
+    check(3.5 * si::OHM);
+    check(3.5 * ucum::MILLIOHM);
+    check(3.0 * cgs::STATC);
+    check(3.0 * cgs::CM3);
+}
+
+#[test]
+fn parsing() {
+    assert_eq!("3.5".parse(), Ok(3.5 * si::ONE));
+    assert_eq!("3.5 s".parse(), Ok(3.5 * si::S));
+    assert_eq!("3.5 m*kg*s^-2".parse(), Ok(3.5 * si::N));
+    assert_eq!("3.5 m*s^-1".parse(), Ok(3.5 * ms::MPS));
+    assert_eq!("3.5 Hz".parse(), Ok(3.5 * ms::HZ));
+    assert_eq!("3.5 m*Hz".parse(), Ok(3.5 * ms::MPS));
+    assert_eq!("3 cm^1.5*g^0.5*s^-1".parse(), Ok(3.0 * cgs::STATC));
+}
+
+#[test]
+fn symbols() {
+    use crate::dim::f64prefixes::*;
+
+    assert_eq!("9.81 N".parse(), Ok(9.81 * si::N));
+    assert_eq!("3 km".parse(), Ok(3.0 * KILO * si::M));
+    assert_eq!("12 psi".parse(), Ok(12.0 * si::PSI));
+    assert_eq!("5 MIN".parse(), Ok(5.0 * si::MIN));
+    assert_eq!("5 min".parse(), Ok(5.0 * si::MIN));
+    assert_eq!("2 mA".parse(), Ok(2.0 * MILLI * si::A));
+    assert_eq!("2 µs".parse(), Ok(2.0 * MICRO * si::S));
+    assert_eq!("4.7 nF".parse(), Ok(4.7 * NANO * si::F));
+    assert_eq!("3 m/s^2".parse(), Ok(3.0 * si::MPS2));
+    assert_eq!("3 N*m/(kg*K)".parse(), Ok(3.0 * si::JPKGK));
+    assert_eq!("3 1/s".parse(), Ok(3.0 * si::HZ));
+    assert_eq!("60 MI/HR".parse(), Ok(60.0 * si::MI / si::HR));
+
+    // Prefixes are tried before ignoring case
+    assert_eq!("5 ns".parse(), Ok(5.0 * NANO * si::S));
+    assert_eq!("5 pm".parse(), Ok(5.0 * PICO * si::M));
+    assert_eq!("5 NS".parse(), Ok(5.0 * si::N * si::S));
+
+    // ... and then ignoring case, with or without a prefix
+    assert_eq!("2 eV".parse(), Ok(2.0 * si::EV));
+    assert_eq!("2 keV".parse(), Ok(2.0 * KILO * si::EV));
+    assert_eq!("2 mL".parse(), Ok(2.0 * MILLI * si::L));
+    assert_eq!("2 Bq".parse(), Ok(2.0 * si::BQ));
+    assert_eq!("2 ml".parse(), Ok(2.0 * MILLI * si::L));
+
+    // Grams in SI, even though its base unit is the kilogram
+    assert_eq!("5 g".parse(), Ok(5.0 * si::G));
+    assert_eq!("500 mg".parse(), Ok(0.5 * si::G));
+
+    // Micro may be a micro sign or a Greek mu
+    assert_eq!("2 \u{b5}m".parse(), Ok(2.0 * MICRO * si::M));
+    assert_eq!("2 \u{3bc}m".parse(), Ok(2.0 * MICRO * si::M));
+
+    // The powers of a symbol are added up before it is scaled
+    assert_eq!("2 km^3000/km^3000".parse(), Ok(2.0 * si::ONE));
+
+    // The value is scaled in the value type
+    assert_eq!("5 MIN".parse(), Ok(si::Second::new(300.0f32)));
+    assert_eq!("3 km".parse(), Ok(si::Meter::new(3000)));
+
+    // UCUM's base unit of mass is the gram
+    assert_eq!("3 kg".parse(), Ok(3.0 * ucum::KG));
+    assert_eq!("3 N".parse(), Ok(3.0 * ucum::N));
+    assert_eq!("3.5 m*kg*s^-2".parse(), Ok(3.5 * ucum::N));
+    assert_eq!("3 mW".parse(), Ok(3.0 * ucum::MILLIW));
+    assert_eq!("3 MW".parse(), Ok(3.0 * MEGA * ucum::W));
+}
+
+#[test]
+fn parse_errors() {
+    use std::num::ParseFloatError;
+    type Error = ParseQuantityError<ParseFloatError>;
+
+    assert!(matches!(
+        "x m".parse::<si::Meter<f64>>(),
+        Err(Error::Value(_))
+    ));
+    assert_eq!(
+        "3.5 furlong".parse::<si::Meter<f64>>(),
+        Err(Error::UnknownUnit)
+    );
+    assert_eq!("3.5 ft".parse::<ms::Meter<f64>>(), Err(Error::UnknownUnit));
+    assert_eq!("3.5 km".parse::<ms::Meter<f64>>(), Err(Error::UnknownUnit));
+    assert_eq!(
+        "3.5 m^x".parse::<si::Meter<f64>>(),
+        Err(Error::InvalidExponent)
+    );
+    assert_eq!("3.5 s".parse::<si::Meter<f64>>(), Err(Error::UnitMismatch));
+    assert_eq!("3.5".parse::<si::Meter<f64>>(), Err(Error::UnitMismatch));
+    assert_eq!(
+        "3.5 m".parse::<si::Unitless<f64>>(),
+        Err(Error::UnitMismatch)
+    );
+    assert_eq!(
+        "3.5 m/(s".parse::<si::MeterPerSecond<f64>>(),
+        Err(Error::MalformedUnits)
+    );
+    assert_eq!(
+        "3 cm^1.5".parse::<si::Meter<f64>>(),
+        Err(Error::InvalidExponent)
+    );
+
+    // An integer can't be scaled by a fraction
+    assert_eq!(
+        "1500 mm".parse::<si::Meter<i32>>(),
+        Err(ParseQuantityError::OutOfRange)
+    );
+    assert_eq!(
+        "3 Gm".parse::<si::Meter<i16>>(),
+        Err(ParseQuantityError::OutOfRange)
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(all(feature = "serde", feature = "serde_test"))]
+
+#[macro_use]
+extern crate dimensioned as dim;
+extern crate serde;
+extern crate serde_test;
+
+use crate::dim::serde_helpers::tagged;
+use crate::dim::{cgs, si};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+/// A quantity serialized with `tagged`, as with `#[serde(with = "tagged")]`
+#[derive(Debug, PartialEq)]
+struct Tagged<Q>(Q);
+
+impl<Q: std::fmt::Display> Serialize for Tagged<Q> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        tagged::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, Q> Deserialize<'de> for Tagged<Q>
+where
+    Q: std::str::FromStr,
+    Q::Err: std::fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        tagged::deserialize(deserializer).map(Tagged)
+    }
+}
+
+#[test]
+fn round_trip() {
+    assert_tokens(&Tagged(3.0 * si::MPS), &[Token::Str("3 m*s^-1")]);
+    assert_tokens(&Tagged(3.5 * si::N), &[Token::Str("3.5 N")]);
+    assert_tokens(&Tagged(0.1 * si::ONE), &[Token::Str("0.1")]);
+    assert_tokens(&Tagged(7 * si::i32consts::M), &[Token::Str("7 m")]);
+    assert_tokens(
+        &Tagged(2.0 * cgs::STATC),
+        &[Token::Str("2 cm^1.5*g^0.5*s^-1")],
+    );
+}
+
+#[test]
+fn mismatched_units() {
+    assert_de_tokens_error::<Tagged<si::Second<f64>>>(
+        &[Token::Str("3 m")],
+        "units do not match the expected units",
+    );
+    assert_de_tokens_error::<Tagged<si::Meter<f64>>>(
+        &[Token::Str("3")],
+        "units do not match the expected units",
+    );
+    assert_de_tokens_error::<Tagged<si::Meter<f64>>>(
+        &[Token::F64(3.0)],
+        "invalid type: floating point `3.0`, expected a quantity with units, such as \"3.5 m*s^-1\"",
+    );
+}
+
+use crate::dim::f64prefixes::*;
+
+serde_in!(mod milliseconds: si::Second<f64> = MILLI * si::S);
+serde_in!(mod minutes: si::Second<f64> = si::MIN);
+serde_in!(mod kilometers: si::Meter<f64> = KILO * si::M);
+serde_in!(pub(crate) mod psi: si::Pascal<f64> = si::PSI);
+
+/// Define a quantity serialized with a `serde_in!` module, as with `#[serde(with = "...")]`
+macro_rules! serialized_in {
+    ($Wrapper:ident, $module:ident, $Quantity:ty) => {
+        #[derive(Debug, PartialEq)]
+        struct $Wrapper($Quantity);
+
+        impl Serialize for $Wrapper {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $module::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $Wrapper {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $module::deserialize(deserializer).map($Wrapper)
+            }
+        }
+    };
+}
+
+serialized_in!(Milliseconds, milliseconds, si::Second<f64>);
+serialized_in!(Minutes, minutes, si::Second<f64>);
+serialized_in!(Kilometers, kilometers, si::Meter<f64>);
+serialized_in!(Psi, psi, si::Pascal<f64>);
+
+#[test]
+fn in_units() {
+    assert_tokens(&Milliseconds(1.5 * si::S), &[Token::F64(1500.0)]);
+    assert_tokens(&Minutes(90.0 * si::S), &[Token::F64(1.5)]);
+    assert_tokens(&Kilometers(2500.0 * si::M), &[Token::F64(2.5)]);
+
+    let pressure = 2.0 * si::PSI;
+    assert_tokens(&Psi(pressure), &[Token::F64(2.0)]);
+}
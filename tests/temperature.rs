@@ -0,0 +1,59 @@
+extern crate dimensioned as dim;
+
+use dim::temperature::Absolute;
+use dim::{si, sia, ucum};
+
+type SiTemperature = Absolute<si::Kelvin<f64>>;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn scales() {
+    let boiling = SiTemperature::from_celsius(100.0);
+    assert!(close(*(boiling.kelvin() / si::K), 373.15));
+    assert!(close(boiling.fahrenheit(), 212.0));
+    assert!(close(boiling.rankine(), 671.67));
+
+    let t = SiTemperature::from_fahrenheit(-40.0);
+    assert!(close(t.celsius(), -40.0));
+
+    let t = SiTemperature::from_rankine(0.0);
+    assert_eq!(t, SiTemperature::new(0.0 * si::K));
+    assert!(close(t.fahrenheit(), -459.67));
+
+    let t = Absolute::<ucum::Kelvin<f32>>::from_celsius(25.0);
+    assert!((*(t.kelvin() / ucum::f32consts::K) - 298.15).abs() < 1e-4);
+}
+
+#[test]
+fn arithmetic() {
+    let a = SiTemperature::from_celsius(20.0);
+    let b = SiTemperature::from_celsius(15.0);
+
+    let diff: si::Kelvin<f64> = a - b;
+    assert!(close(*(diff / si::K), 5.0));
+
+    assert_eq!(b + diff, a);
+    assert_eq!(diff + b, a);
+    assert_eq!(a - diff, b);
+    assert!(a > b);
+
+    let a = Absolute::new(300.0 * sia::K);
+    assert_eq!(5.0 * sia::K + a, Absolute::new(305.0 * sia::K));
+
+    let a = Absolute::new(300.0 * ucum::K);
+    assert_eq!(a + 9.0 * ucum::DEGR, Absolute::new(305.0 * ucum::K));
+    assert_eq!(9.0 * ucum::DEGR + a, Absolute::new(305.0 * ucum::K));
+    assert_eq!(3.0 * ucum::K + 2.0 * ucum::K, 5.0 * ucum::K);
+}
+
+#[test]
+fn display() {
+    // points are written in kelvin, like differences
+    assert_eq!(
+        format!("{}", Absolute::new(300.0 * si::K)),
+        format!("{}", 300.0 * si::K)
+    );
+}